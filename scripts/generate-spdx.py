@@ -0,0 +1,138 @@
+#!/usr/bin/env python3
+#
+# Generates `src/spdx.rs` from a checkout of the SPDX license list data:
+#
+#     git clone https://github.com/spdx/license-list-data
+#     ./scripts/generate-spdx.py license-list-data > src/spdx.rs
+#
+# The SPDX data doesn't classify licenses, so the categories are assigned by
+# the rules below. They are only a best effort hint, used when the
+# compatibility matrix doesn't know about a license, so err on the side of
+# the more restrictive category when unsure. Anything the rules don't
+# recognise is `Other`, which gives an unknown verdict rather than a guess.
+
+import json
+import os
+import sys
+
+PUBLIC_DOMAIN = {
+    "0BSD", "CC-PDDC", "CC-PDM-1.0", "CC0-1.0", "MIT-0", "PDDL-1.0", "SAX-PD",
+    "SAX-PD-2.0", "Unlicense", "WTFPL", "blessing", "libselinux-1.0",
+}
+
+OTHER = {
+    "BUSL-1.1", "Elastic-2.0", "Hippocratic-2.1", "JSON", "Parity-6.0.0",
+    "Parity-7.0.0", "PolyForm-Noncommercial-1.0.0",
+    "PolyForm-Small-Business-1.0.0",
+}
+
+NETWORK_COPYLEFT = (
+    "AGPL-", "CAL-", "CPAL-", "EUPL-", "OSL-", "RPL-", "RPSL-", "SSPL-",
+    "Watcom-",
+)
+
+WEAK_COPYLEFT = (
+    "APSL-", "CDDL-", "CECILL-C", "CPL-", "EPL-", "ErlPL-", "IPL-", "LGPL",
+    "MPL-", "MS-PL", "MS-RL", "NPL-", "NPOSL-", "OFL-", "SISSL", "SPL-",
+    "YPL-",
+)
+
+STRONG_COPYLEFT = (
+    "BSD-Protection", "CC-BY-SA-", "CECILL-1", "CECILL-2", "GFDL-", "GPL-",
+    "QPL-", "Sleepycat", "xinetd",
+)
+
+PERMISSIVE = (
+    "AFL-", "Apache-", "Artistic-2.0", "Beerware", "BlueOak-", "BSD-",
+    "BSL-1.0", "bzip2-", "CC-BY-", "CDLA-Permissive-", "CECILL-B", "curl",
+    "ECL-", "EFL-", "FSFAP", "FSFULLR", "FTL", "HPND", "ICU", "IJG",
+    "Info-ZIP", "ISC", "Libpng", "libpng-", "libtiff", "MirOS", "MIT",
+    "MulanPSL-", "NCSA", "NTP", "OLDAP-", "OpenSSL", "PHP-", "PostgreSQL",
+    "PSF-", "Python-", "Ruby", "Spencer-", "TCL", "Unicode-3.0",
+    "Unicode-DFS-", "UPL-", "W3C", "X11", "Xnet", "Zlib",
+    "zlib-acknowledgement", "ZPL-",
+)
+
+
+def category(license):
+    id = license["licenseId"]
+    if id in PUBLIC_DOMAIN:
+        return "PublicDomain"
+    if id in OTHER or "-NC-" in id + "-" or "-ND-" in id + "-":
+        return "Other"
+    if id.startswith(NETWORK_COPYLEFT):
+        return "NetworkCopyleft"
+    # The deprecated `GPL-2.0-with-classpath-exception` style identifiers
+    # allow linking, so are closer to the LGPL than the GPL
+    if id.startswith(WEAK_COPYLEFT) or (id.startswith("GPL-") and "-with-" in id):
+        return "WeakCopyleft"
+    if id.startswith(STRONG_COPYLEFT):
+        return "StrongCopyleft"
+    if id.startswith(PERMISSIVE):
+        return "Permissive"
+    return "Other"
+
+
+def string(s):
+    return '"' + s.replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+def main(root):
+    with open(os.path.join(root, "json", "licenses.json")) as f:
+        licenses = json.load(f)
+    with open(os.path.join(root, "json", "exceptions.json")) as f:
+        exceptions = json.load(f)
+
+    print("//! The [SPDX License List](https://spdx.org/licenses/) version {}".format(licenses["licenseListVersion"]))
+    print("//!")
+    print("//! This file was generated by `scripts/generate-spdx.py`, do not edit it by hand")
+    print("""
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
+pub enum Category {
+    PublicDomain,
+    Permissive,
+    WeakCopyleft,
+    StrongCopyleft,
+    NetworkCopyleft,
+    Other,
+}
+
+#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
+pub struct LicenseId {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub deprecated: bool,
+    pub osi_approved: bool,
+    pub fsf_libre: bool,
+    pub category: Category,
+}
+
+#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
+pub struct ExceptionId {
+    pub id: &'static str,
+    pub deprecated: bool,
+}
+""")
+    print("pub const VERSION: &str = {};".format(string(licenses["licenseListVersion"])))
+    print()
+    print("pub const LICENSES: &[LicenseId] = &[")
+    for license in sorted(licenses["licenses"], key=lambda l: l["licenseId"].lower()):
+        print("    LicenseId {{ id: {}, name: {}, deprecated: {}, osi_approved: {}, fsf_libre: {}, category: Category::{} }},".format(
+            string(license["licenseId"]),
+            string(license["name"]),
+            str(license["isDeprecatedLicenseId"]).lower(),
+            str(license.get("isOsiApproved", False)).lower(),
+            str(license.get("isFsfLibre", False)).lower(),
+            category(license)))
+    print("];")
+    print()
+    print("pub const EXCEPTIONS: &[ExceptionId] = &[")
+    for exception in sorted(exceptions["exceptions"], key=lambda e: e["licenseExceptionId"].lower()):
+        print("    ExceptionId {{ id: {}, deprecated: {} }},".format(
+            string(exception["licenseExceptionId"]),
+            str(exception["isDeprecatedLicenseId"]).lower()))
+    print("];")
+
+
+if __name__ == "__main__":
+    main(sys.argv[1])
@@ -1,28 +1,47 @@
+use std::collections::{BTreeMap, HashMap};
+use std::env;
 use std::io::{self, Write};
 use std::fs::{self, File};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use cargo::{Config, CargoResult};
 use cargo::core::{Package, Shell};
+use cargo::core::dependency::Kind;
+use crypto_hash::{self, Algorithm};
+use serde_json;
 
-use license::License;
-use licensed::Licensed;
-use options::Bundle;
-use discovery::{
-    Confidence, LicenseText, find_generic_license_text, find_license_text
-};
+use license::{Exception, License};
+use licensed::{Licensed, Licenses};
+use load::Graph;
+use options::{Bundle, CycloneDxFormat, SpdxFormat};
+use settings::{Settings, Treatment};
+use spdx;
+use discovery::{Confidence, Discovery, LicenseFile, LicenseText, read};
+use template::Template;
 
 struct Context<'a> {
     roots_name: String,
     packages: &'a [Package],
+    graph: &'a Graph,
+    licenses: &'a Licenses,
+    discovery: &'a Discovery,
     shell: &'a mut Shell,
+    /// Whether to use the standard text of licenses that packages don't
+    /// include the text of
+    fallback_to_template: bool,
 
     missing_license: bool,
     low_quality_license: bool,
 }
 
-pub fn run(roots: &[Package], mut packages: Vec<Package>, config: &Config, variant: Bundle) -> CargoResult<()> {
-    packages.sort_by(|p1, p2| (p1.name(), p1.version()).cmp(&(p2.name(), p2.version())));
+pub fn run(roots: &[Package], mut graph: Graph, config: &Config, settings: &Settings, licenses: &Licenses, variant: Bundle, fallback_to_template: bool) -> CargoResult<()> {
+    let included = graph.packages.iter()
+        .filter(|package| settings.treatment(graph.kinds(package.package_id())) != Treatment::Ignore)
+        .cloned()
+        .collect();
+    graph.packages = included;
+    graph.packages.sort_by(|p1, p2| (p1.name(), p1.version()).cmp(&(p2.name(), p2.version())));
 
     let roots_name = {
         if roots.len() == 1 {
@@ -42,12 +61,23 @@ pub fn run(roots: &[Package], mut packages: Vec<Package>, config: &Config, varia
     };
     let mut context = Context {
         roots_name,
-        packages: &packages,
+        packages: &graph.packages,
+        graph: &graph,
+        licenses,
+        discovery: &settings.discovery,
         shell: &mut config.shell(),
+        fallback_to_template,
         missing_license: false,
         low_quality_license: false,
     };
 
+    for package in context.packages {
+        let license = context.licenses.license(package);
+        for (path, classification) in context.discovery.find_mismatched_license_texts(package, &license)? {
+            context.shell.warn(format_args!("{} uses license {} but {} looks like {} ({:.0}% match)", package.name(), license, path.display(), classification.license, classification.score * 100.0))?;
+        }
+    }
+
     match variant {
         Bundle::Inline { file } => {
             if let Some(file) = file {
@@ -63,6 +93,27 @@ pub fn run(roots: &[Package], mut packages: Vec<Package>, config: &Config, varia
                 name_only(&mut context, &mut io::stdout())?;
             }
         }
+        Bundle::CopyrightNotices { file } => {
+            if let Some(file) = file {
+                copyright_notices(&mut context, &mut File::create(file)?)?;
+            } else {
+                copyright_notices(&mut context, &mut io::stdout())?;
+            }
+        }
+        Bundle::Markdown { file } => {
+            if let Some(file) = file {
+                markdown(&mut context, &mut File::create(file)?)?;
+            } else {
+                markdown(&mut context, &mut io::stdout())?;
+            }
+        }
+        Bundle::Html { file } => {
+            if let Some(file) = file {
+                html(&mut context, &mut File::create(file)?)?;
+            } else {
+                html(&mut context, &mut io::stdout())?;
+            }
+        }
         Bundle::Source { file } => {
             if let Some(file) = file {
                 source(&mut context, &mut File::create(file)?)?;
@@ -77,6 +128,20 @@ pub fn run(roots: &[Package], mut packages: Vec<Package>, config: &Config, varia
                 split(&mut context, &mut io::stdout(), dir)?;
             }
         }
+        Bundle::Spdx { file, format } => {
+            if let Some(file) = file {
+                spdx(&mut context, roots, &mut File::create(file)?, format)?;
+            } else {
+                spdx(&mut context, roots, &mut io::stdout(), format)?;
+            }
+        }
+        Bundle::CycloneDx { file, format } => {
+            if let Some(file) = file {
+                cyclonedx(&mut context, roots, &mut File::create(file)?, format)?;
+            } else {
+                cyclonedx(&mut context, roots, &mut io::stdout(), format)?;
+            }
+        }
     }
 
     if context.missing_license {
@@ -112,7 +177,7 @@ fn inline(context: &mut Context, out: &mut io::Write) -> CargoResult<()> {
     writeln!(out, "The {} uses some third party libraries under their own license terms:", context.roots_name)?;
     writeln!(out)?;
     for package in context.packages {
-        writeln!(out, " * {} {} under the terms of {}:", package.name(), package.version(), package.license())?;
+        writeln!(out, " * {} {}{} under the terms of {}:", package.name(), package.version(), usage(context, package), context.licenses.license(package))?;
         writeln!(out)?;
         inline_package(context, package, out)?;
         writeln!(out)?;
@@ -124,11 +189,190 @@ fn name_only(context: &mut Context, out: &mut io::Write) -> CargoResult<()> {
     writeln!(out, "The {} uses some third party libraries under their own license terms:", context.roots_name)?;
     writeln!(out)?;
     for package in context.packages {
-        writeln!(out, " * {} {} under the terms of {}", package.name(), package.version(), package.license())?;
+        writeln!(out, " * {} {}{} under the terms of {}", package.name(), package.version(), usage(context, package), context.licenses.license(package))?;
+    }
+    Ok(())
+}
+
+fn copyright_notices(context: &mut Context, out: &mut io::Write) -> CargoResult<()> {
+    writeln!(out, "The {} uses some third party libraries with the following copyright notices:", context.roots_name)?;
+    for package in context.packages {
+        writeln!(out)?;
+        writeln!(out, "{} {}{}:", package.name(), package.version(), usage(context, package))?;
+        let license = context.licenses.license(package);
+        let copyrights = context.discovery.find_copyrights(package, &license)?;
+        if copyrights.is_empty() {
+            context.shell.warn(format_args!("{} has no copyright notices and no authors", package.name()))?;
+        }
+        for copyright in copyrights {
+            if copyright.path.is_some() {
+                writeln!(out, "    {}", copyright.notice)?;
+            } else {
+                writeln!(out, "    {} (from the package authors)", copyright.notice)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn markdown(context: &mut Context, out: &mut io::Write) -> CargoResult<()> {
+    let (package_texts, texts) = shared_texts(context)?;
+
+    writeln!(out, "# Third party licenses")?;
+    writeln!(out)?;
+    writeln!(out, "The {} uses some third party libraries under their own license terms:", context.roots_name)?;
+    writeln!(out)?;
+    writeln!(out, "| Crate | Version | License |")?;
+    writeln!(out, "| --- | --- | --- |")?;
+    for package in context.packages {
+        writeln!(out, "| [{}](#{}) | {} | {} |", markdown_escape(package.name().as_str()), anchor(package), package.version(), markdown_escape(&context.licenses.license(package).to_string()))?;
+    }
+
+    for (package, indices) in context.packages.iter().zip(&package_texts) {
+        writeln!(out)?;
+        writeln!(out, "<a id=\"{}\"></a>", anchor(package))?;
+        writeln!(out)?;
+        writeln!(out, "## {} {}", markdown_escape(package.name().as_str()), package.version())?;
+        writeln!(out)?;
+        writeln!(out, "Used{} under the terms of {}.", usage(context, package), markdown_escape(&context.licenses.license(package).to_string()))?;
+        if !indices.is_empty() {
+            writeln!(out)?;
+            for &index in indices {
+                writeln!(out, " * [{}](#license-{})", markdown_escape(&texts[index].title()), index + 1)?;
+            }
+        }
+    }
+
+    if !texts.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "# License texts")?;
+    }
+    for (index, section) in texts.iter().enumerate() {
+        let text = section.text.as_ref().map_or("", |text| &text[..]);
+        let users = context.packages.iter().zip(&package_texts)
+            .filter(|&(_, indices)| indices.contains(&index))
+            .map(|(package, _)| format!("[{} {}](#{})", markdown_escape(package.name().as_str()), package.version(), anchor(package)))
+            .collect::<Vec<_>>();
+        writeln!(out)?;
+        writeln!(out, "<a id=\"license-{}\"></a>", index + 1)?;
+        writeln!(out)?;
+        writeln!(out, "## {}", markdown_escape(&section.title()))?;
+        writeln!(out)?;
+        writeln!(out, "Used by {}.", users.join(", "))?;
+        writeln!(out)?;
+        write!(out, "{}", markdown_code_block(text))?;
+    }
+    Ok(())
+}
+
+/// An identifier for a package usable in links to it
+fn anchor(package: &Package) -> String {
+    format!("{}-{}", package.name(), package.version())
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c.to_ascii_lowercase() } else { '-' })
+        .collect()
+}
+
+fn markdown_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "\\`*_[]<>|#&".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// A text as a fenced code block, the fence has to be longer than any run of
+/// backticks in the text
+fn markdown_code_block(text: &str) -> String {
+    let mut fence = "```".to_owned();
+    while text.contains(&fence[..]) {
+        fence.push('`');
+    }
+    format!("{}text\n{}\n{}\n", fence, text.trim_end(), fence)
+}
+
+fn html(context: &mut Context, out: &mut io::Write) -> CargoResult<()> {
+    let mut license_to_packages = HashMap::new();
+    for package in context.packages {
+        license_to_packages
+            .entry(context.licenses.license(package))
+            .or_insert_with(Vec::new)
+            .push(package);
+    }
+    let mut license_to_packages = license_to_packages.into_iter().collect::<Vec<_>>();
+    license_to_packages.sort_by(|&(ref l1, _), &(ref l2, _)| l1.cmp(l2));
+
+    out.write_all(b"<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>Third party licenses</title>
+<style>
+body { font-family: sans-serif; max-width: 60em; margin: 0 auto; padding: 1em; }
+summary { cursor: pointer; padding: 0.25em 0; }
+pre { white-space: pre-wrap; background: #f4f4f4; padding: 1em; }
+.usage { color: #666; }
+</style>
+</head>
+<body>
+<h1>Third party licenses</h1>
+")?;
+    writeln!(out, "<p>The {} uses some third party libraries under their own license terms:</p>", xml_escape(&context.roots_name))?;
+
+    writeln!(out, "<ul>")?;
+    for &(ref license, ref packages) in &license_to_packages {
+        writeln!(out, "<li><a href=\"#{}\">{}</a>: {}</li>", license_anchor(license), xml_escape(&license.to_string()), packages.iter()
+            .map(|package| format!("<a href=\"#{}\">{}</a>", anchor(package), xml_escape(package.name().as_str())))
+            .collect::<Vec<_>>()
+            .join(", "))?;
     }
+    writeln!(out, "</ul>")?;
+
+    for (license, packages) in license_to_packages {
+        writeln!(out, "<section id=\"{}\">", license_anchor(&license))?;
+        writeln!(out, "<h2>{}</h2>", xml_escape(&license.to_string()))?;
+        for package in packages {
+            let metadata = package.manifest().metadata();
+            writeln!(out, "<details id=\"{}\">", anchor(package))?;
+            write!(out, "<summary>{} {}<span class=\"usage\">{}</span>", xml_escape(package.name().as_str()), xml_escape(&package.version().to_string()), xml_escape(usage(context, package)))?;
+            for &(name, url) in &[("repository", &metadata.repository), ("homepage", &metadata.homepage)] {
+                // Only link to web pages, not whatever else a manifest says
+                if let Some(ref url) = *url {
+                    if url.starts_with("https://") || url.starts_with("http://") {
+                        write!(out, " &middot; <a href=\"{}\">{}</a>", xml_escape(url), name)?;
+                    }
+                }
+            }
+            writeln!(out, "</summary>")?;
+            for section in package_sections(context, package)? {
+                if let Some(ref text) = section.text {
+                    writeln!(out, "<h3>{}</h3>", xml_escape(&section.title()))?;
+                    writeln!(out, "{}", html_pre(text))?;
+                }
+            }
+            writeln!(out, "</details>")?;
+        }
+        writeln!(out, "</section>")?;
+    }
+
+    out.write_all(b"</body>
+</html>
+")?;
     Ok(())
 }
 
+/// A text shown as it is, including any markup in it
+fn html_pre(text: &str) -> String {
+    format!("<pre>{}</pre>", xml_escape(text.trim_end()))
+}
+
+fn license_anchor(license: &License) -> String {
+    format!("license-{}", spdx_idstring(&license.to_string()))
+}
+
 fn source(context: &mut Context, out: &mut io::Write) -> CargoResult<()> {
     out.write_all(b"
 //! Licenses of dependencies
@@ -149,6 +393,7 @@ pub struct LicensedCrate {
     pub name: &'static str,
     pub version: &'static str,
     pub licenses: Licenses,
+    pub notice: Option<&'static str>,
 }
 
 pub const CRATES: &[LicensedCrate] = &[
@@ -165,194 +410,1029 @@ fn split<P: AsRef<Path>>(context: &mut Context, out: &mut io::Write, dir: P) ->
     writeln!(out, "The {} uses some third party libraries under their own license terms:", context.roots_name)?;
     writeln!(out)?;
     for package in context.packages {
-        writeln!(out, " * {} {} under the terms of {}", package.name(), package.version(), package.license())?;
+        writeln!(out, " * {} {}{} under the terms of {}", package.name(), package.version(), usage(context, package), context.licenses.license(package))?;
         split_package(context, package, dir.as_ref())?;
     }
     Ok(())
 }
 
-fn inline_package(context: &mut Context, package: &Package, out: &mut io::Write) -> CargoResult<()> {
-    let license = package.license();
-    if let Some(text) = find_generic_license_text(package, &license)? {
-        match text.confidence {
-            Confidence::Confident => (),
-            Confidence::SemiConfident => {
-                context.shell.warn(format_args!("{} has only a low-confidence candidate for license {}:", package.name(), license))?;
-                context.shell.warn(format_args!("    {}", text.path.display()))?;
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxDocument {
+    spdx_version: &'static str,
+    data_license: &'static str,
+    #[serde(rename = "SPDXID")]
+    spdx_id: &'static str,
+    name: String,
+    document_namespace: String,
+    creation_info: SpdxCreationInfo,
+    document_describes: Vec<String>,
+    packages: Vec<SpdxPackage>,
+    relationships: Vec<SpdxRelationship>,
+    has_extracted_licensing_infos: Vec<SpdxExtractedLicense>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxCreationInfo {
+    created: String,
+    creators: Vec<String>,
+    license_list_version: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxPackage {
+    name: String,
+    #[serde(rename = "SPDXID")]
+    spdx_id: String,
+    version_info: String,
+    download_location: String,
+    files_analyzed: bool,
+    license_concluded: String,
+    license_declared: String,
+    copyright_text: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    attribution_texts: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxRelationship {
+    spdx_element_id: String,
+    relationship_type: &'static str,
+    related_spdx_element: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpdxExtractedLicense {
+    license_id: String,
+    extracted_text: String,
+    name: String,
+}
+
+fn spdx(context: &mut Context, roots: &[Package], out: &mut io::Write, format: SpdxFormat) -> CargoResult<()> {
+    let mut ids = HashMap::new();
+    for package in context.packages {
+        let mut id = format!("SPDXRef-Package-{}-{}", spdx_idstring(package.name().as_str()), spdx_idstring(&package.version().to_string()));
+        if ids.values().any(|existing| *existing == id) {
+            id = format!("{}-{}", id, ids.len());
+        }
+        ids.insert(package.package_id().clone(), id);
+    }
+
+    let created = timestamp();
+
+    let mut packages = Vec::new();
+    let mut relationships = Vec::new();
+    let mut extracted = Vec::new();
+    for package in context.packages {
+        let id = &ids[package.package_id()];
+        let declared = package.license();
+        let concluded = context.licenses.license(package);
+        packages.push(SpdxPackage {
+            name: package.name().to_string(),
+            spdx_id: id.clone(),
+            version_info: package.version().to_string(),
+            download_location: spdx_download_location(package),
+            files_analyzed: false,
+            license_concluded: spdx_expression(context, package, id, &concluded, &mut extracted)?,
+            license_declared: spdx_expression(context, package, id, &declared, &mut extracted)?,
+            copyright_text: "NOASSERTION",
+            attribution_texts: notice(context, package)?.into_iter().map(|notice| notice.text).collect(),
+        });
+        for edge in context.graph.dependencies(package.package_id()) {
+            if let Some(dep) = ids.get(&edge.id) {
+                relationships.push(match edge.kind {
+                    Kind::Normal => SpdxRelationship {
+                        spdx_element_id: id.clone(),
+                        relationship_type: "DEPENDS_ON",
+                        related_spdx_element: dep.clone(),
+                    },
+                    Kind::Build => SpdxRelationship {
+                        spdx_element_id: dep.clone(),
+                        relationship_type: "BUILD_DEPENDENCY_OF",
+                        related_spdx_element: id.clone(),
+                    },
+                    Kind::Development => SpdxRelationship {
+                        spdx_element_id: dep.clone(),
+                        relationship_type: "DEV_DEPENDENCY_OF",
+                        related_spdx_element: id.clone(),
+                    },
+                });
+            }
+        }
+    }
+
+    let namespace = {
+        let mut hashed = created.clone();
+        for package in context.packages {
+            hashed += &package.package_id().to_string();
+        }
+        crypto_hash::hex_digest(Algorithm::SHA256, hashed.as_bytes())
+    };
+    let name = roots.iter().map(|root| format!("{}-{}", root.name(), root.version())).collect::<Vec<_>>().join("+");
+
+    let document = SpdxDocument {
+        spdx_version: "SPDX-2.3",
+        data_license: "CC0-1.0",
+        spdx_id: "SPDXRef-DOCUMENT",
+        document_namespace: format!("https://spdx.org/spdxdocs/{}-{}", name, namespace),
+        name,
+        creation_info: SpdxCreationInfo {
+            created,
+            creators: vec![format!("Tool: cargo-lichking-{}", crate_version!())],
+            license_list_version: spdx::VERSION.split('.').take(2).collect::<Vec<_>>().join("."),
+        },
+        document_describes: roots.iter().filter_map(|root| ids.get(root.package_id()).cloned()).collect(),
+        packages,
+        relationships,
+        has_extracted_licensing_infos: extracted,
+    };
+
+    match format {
+        SpdxFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, &document)?;
+            writeln!(out)?;
+        }
+        SpdxFormat::TagValue => {
+            spdx_tag_value(&document, out)?;
+        }
+    }
+    Ok(())
+}
+
+fn spdx_tag_value(document: &SpdxDocument, out: &mut io::Write) -> CargoResult<()> {
+    writeln!(out, "SPDXVersion: {}", document.spdx_version)?;
+    writeln!(out, "DataLicense: {}", document.data_license)?;
+    writeln!(out, "SPDXID: {}", document.spdx_id)?;
+    writeln!(out, "DocumentName: {}", document.name)?;
+    writeln!(out, "DocumentNamespace: {}", document.document_namespace)?;
+    for creator in &document.creation_info.creators {
+        writeln!(out, "Creator: {}", creator)?;
+    }
+    writeln!(out, "Created: {}", document.creation_info.created)?;
+    writeln!(out, "LicenseListVersion: {}", document.creation_info.license_list_version)?;
+    for described in &document.document_describes {
+        writeln!(out, "Relationship: {} DESCRIBES {}", document.spdx_id, described)?;
+    }
+
+    for package in &document.packages {
+        writeln!(out)?;
+        writeln!(out, "PackageName: {}", package.name)?;
+        writeln!(out, "SPDXID: {}", package.spdx_id)?;
+        writeln!(out, "PackageVersion: {}", package.version_info)?;
+        writeln!(out, "PackageDownloadLocation: {}", package.download_location)?;
+        writeln!(out, "FilesAnalyzed: {}", package.files_analyzed)?;
+        writeln!(out, "PackageLicenseConcluded: {}", package.license_concluded)?;
+        writeln!(out, "PackageLicenseDeclared: {}", package.license_declared)?;
+        writeln!(out, "PackageCopyrightText: {}", package.copyright_text)?;
+        for text in &package.attribution_texts {
+            writeln!(out, "PackageAttributionText: <text>{}</text>", spdx_text(text))?;
+        }
+    }
+
+    if !document.relationships.is_empty() {
+        writeln!(out)?;
+    }
+    for relationship in &document.relationships {
+        writeln!(out, "Relationship: {} {} {}", relationship.spdx_element_id, relationship.relationship_type, relationship.related_spdx_element)?;
+    }
+
+    for license in &document.has_extracted_licensing_infos {
+        writeln!(out)?;
+        writeln!(out, "LicenseID: {}", license.license_id)?;
+        writeln!(out, "ExtractedText: <text>{}</text>", spdx_text(&license.extracted_text))?;
+        writeln!(out, "LicenseName: {}", license.name)?;
+    }
+    Ok(())
+}
+
+/// Tag-value text fields can't be escaped, so break up anything in the text
+/// that would end them early
+fn spdx_text(s: &str) -> String {
+    s.replace("</text>", "&lt;/text&gt;")
+}
+
+/// SPDX identifiers may only contain letters, numbers, `.` and `-`
+fn spdx_idstring(s: &str) -> String {
+    s.chars().map(|c| if c.is_ascii_alphanumeric() || c == '.' { c } else { '-' }).collect()
+}
+
+fn spdx_download_location(package: &Package) -> String {
+    let source_id = package.package_id().source_id();
+    if source_id.is_default_registry() {
+        format!("https://crates.io/api/v1/crates/{}/{}/download", package.name(), package.version())
+    } else if source_id.is_git() {
+        match source_id.precise() {
+            Some(rev) => format!("git+{}@{}", source_id.url(), rev),
+            None => format!("git+{}", source_id.url()),
+        }
+    } else {
+        "NOASSERTION".to_owned()
+    }
+}
+
+/// Licenses that aren't on the SPDX list are referred to with a `LicenseRef-`
+/// and have their text included in the document
+fn spdx_expression(context: &mut Context, package: &Package, package_id: &str, license: &License, extracted: &mut Vec<SpdxExtractedLicense>) -> CargoResult<String> {
+    Ok(match *license {
+        License::Unspecified => "NOASSERTION".to_owned(),
+        License::Spdx(license) => license.id.to_owned(),
+        License::With(ref base, Exception::Spdx(exception)) => {
+            format!("{} WITH {}", spdx_expression(context, package, package_id, base, extracted)?, exception.id)
+        }
+        License::And(ref licenses) => {
+            let mut expressions = Vec::new();
+            for license in licenses {
+                let expression = spdx_expression(context, package, package_id, license, extracted)?;
+                expressions.push(match *license {
+                    License::Or(_) => format!("({})", expression),
+                    _ => expression,
+                });
             }
-            Confidence::Unsure => {
-                context.shell.error(format_args!("{} has only a very low-confidence candidate for license {}:", package.name(), license))?;
-                context.shell.error(format_args!("    {}", text.path.display()))?;
+            expressions.join(" AND ")
+        }
+        License::Or(ref licenses) => {
+            let mut expressions = Vec::new();
+            for license in licenses {
+                expressions.push(spdx_expression(context, package, package_id, license, extracted)?);
             }
+            expressions.join(" OR ")
         }
-        for line in text.text.lines() {
-            writeln!(out, "    {}", line)?;
+        License::Custom(_) | License::File(_) | License::With(_, Exception::Custom(_)) => {
+            let id = format!("LicenseRef-{}-{}", &package_id["SPDXRef-Package-".len()..], spdx_idstring(&match *license {
+                License::File(ref path) => path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default(),
+                ref license => license.to_string(),
+            }));
+            if !extracted.iter().any(|existing| existing.license_id == id) {
+                let text = match *license {
+                    License::File(ref path) => match read(path) {
+                        Ok(text) => text,
+                        Err(err) => {
+                            context.shell.warn(format_args!("could not read {} for {} {}: {}", path.display(), package.name(), package.version(), err))?;
+                            format!("The text of {} could not be read from {}", license, path.display())
+                        }
+                    },
+                    ref license => {
+                        let mut texts = context.discovery.find_license_text(package, license)?;
+                        if texts.is_empty() {
+                            texts.extend(generic_license_text(context, package, license)?);
+                        }
+                        match choose(context, package, license, texts)? {
+                            Some(text) => with_exceptions(context, package, license, text.text)?,
+                            None => format!("The text of {} could not be found in {} {}", license, package.name(), package.version()),
+                        }
+                    }
+                };
+                extracted.push(SpdxExtractedLicense {
+                    license_id: id.clone(),
+                    extracted_text: text,
+                    name: license.to_string(),
+                });
+            }
+            id
+        }
+    })
+}
+
+/// The current time as an ISO 8601 UTC timestamp, or the time from
+/// `SOURCE_DATE_EPOCH` for reproducible builds
+fn timestamp() -> String {
+    let secs = env::var("SOURCE_DATE_EPOCH").ok()
+        .and_then(|epoch| epoch.parse().ok())
+        .unwrap_or_else(|| SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or(0));
+    iso8601(secs)
+}
+
+/// Formats seconds since the Unix epoch as an ISO 8601 UTC timestamp
+fn iso8601(secs: u64) -> String {
+    // Converts days since the epoch to a civil date, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = secs / 86400 + 719_468;
+    let era = days / 146_097;
+    let doe = days - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    let secs = secs % 86400;
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CycloneDxBom {
+    bom_format: &'static str,
+    spec_version: &'static str,
+    serial_number: String,
+    version: u32,
+    metadata: CycloneDxMetadata,
+    components: Vec<CycloneDxComponent>,
+    dependencies: Vec<CycloneDxDependency>,
+}
+
+#[derive(Serialize)]
+struct CycloneDxMetadata {
+    timestamp: String,
+    tools: CycloneDxTools,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    component: Option<CycloneDxComponent>,
+}
+
+#[derive(Serialize)]
+struct CycloneDxTools {
+    components: Vec<CycloneDxTool>,
+}
+
+#[derive(Serialize)]
+struct CycloneDxTool {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+struct CycloneDxComponent {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    #[serde(rename = "bom-ref")]
+    bom_ref: String,
+    name: String,
+    version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<&'static str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    licenses: Vec<CycloneDxLicenseChoice>,
+    purl: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    evidence: Option<CycloneDxEvidence>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum CycloneDxLicenseChoice {
+    Expression(String),
+    License(CycloneDxLicense),
+}
+
+#[derive(Serialize)]
+struct CycloneDxLicense {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<CycloneDxText>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CycloneDxText {
+    content_type: &'static str,
+    encoding: &'static str,
+    content: String,
+}
+
+#[derive(Serialize)]
+struct CycloneDxEvidence {
+    licenses: Vec<CycloneDxLicenseChoice>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CycloneDxDependency {
+    #[serde(rename = "ref")]
+    reference: String,
+    depends_on: Vec<String>,
+}
+
+fn cyclonedx(context: &mut Context, roots: &[Package], out: &mut io::Write, format: CycloneDxFormat) -> CargoResult<()> {
+    let purls = context.packages.iter()
+        .map(|package| (package.package_id().clone(), purl(package)))
+        .collect::<HashMap<_, _>>();
+    let refs = context.packages.iter()
+        .map(|package| (package.package_id().clone(), bom_ref(package, &purls[package.package_id()])))
+        .collect::<HashMap<_, _>>();
+
+    let mut metadata_component = None;
+    let mut components = Vec::new();
+    let mut dependencies = Vec::new();
+    for package in context.packages {
+        let component = cyclonedx_component(context, package, &purls[package.package_id()], &refs[package.package_id()])?;
+        // A single root describes what the bill of materials is for,
+        // otherwise the roots are just more components
+        if roots.len() == 1 && roots[0].package_id() == package.package_id() {
+            metadata_component = Some(component);
+        } else {
+            components.push(component);
         }
+        // A package can depend on another in more than one way, e.g. as both
+        // a normal and a build dependency
+        let mut depends_on = context.graph.dependencies(package.package_id()).iter()
+            .filter_map(|edge| refs.get(&edge.id).cloned())
+            .collect::<Vec<_>>();
+        depends_on.sort();
+        depends_on.dedup();
+        dependencies.push(CycloneDxDependency {
+            reference: refs[package.package_id()].clone(),
+            depends_on,
+        });
+    }
+
+    let timestamp = timestamp();
+    let serial_number = {
+        let mut hashed = timestamp.clone();
+        for package in context.packages {
+            hashed += &package.package_id().to_string();
+        }
+        let hash = crypto_hash::hex_digest(Algorithm::SHA256, hashed.as_bytes());
+        // Formatted as a version 4 UUID
+        format!("urn:uuid:{}-{}-4{}-a{}-{}", &hash[0..8], &hash[8..12], &hash[13..16], &hash[17..20], &hash[20..32])
+    };
+
+    let bom = CycloneDxBom {
+        bom_format: "CycloneDX",
+        spec_version: "1.5",
+        serial_number,
+        version: 1,
+        metadata: CycloneDxMetadata {
+            timestamp,
+            tools: CycloneDxTools {
+                components: vec![CycloneDxTool {
+                    kind: "application",
+                    name: "cargo-lichking",
+                    version: crate_version!(),
+                }],
+            },
+            component: metadata_component,
+        },
+        components,
+        dependencies,
+    };
+
+    match format {
+        CycloneDxFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, &bom)?;
+            writeln!(out)?;
+        }
+        CycloneDxFormat::Xml => {
+            cyclonedx_xml(&bom, out)?;
+        }
+    }
+    Ok(())
+}
+
+fn cyclonedx_component(context: &mut Context, package: &Package, purl: &str, bom_ref: &str) -> CargoResult<CycloneDxComponent> {
+    let license = context.licenses.license(package);
+
+    let licenses = match license {
+        License::Unspecified => vec![],
+        ref license if license.simple_licenses().iter().all(|license| is_spdx(license)) => {
+            vec![CycloneDxLicenseChoice::Expression(license.to_string())]
+        }
+        ref license => {
+            vec![CycloneDxLicenseChoice::License(CycloneDxLicense {
+                id: None,
+                name: Some(license.to_string()),
+                text: None,
+            })]
+        }
+    };
+
+    let evidence = cyclonedx_evidence(context, package, &license)?;
+
+    Ok(CycloneDxComponent {
+        kind: "library",
+        bom_ref: bom_ref.to_owned(),
+        name: package.name().to_string(),
+        version: package.version().to_string(),
+        // Only dev dependencies are sure not to be part of what is
+        // distributed, build dependencies can generate code that is
+        scope: if context.graph.is_test_only(package.package_id()) { Some("excluded") } else { None },
+        licenses,
+        purl: purl.to_owned(),
+        evidence: if evidence.is_empty() { None } else { Some(CycloneDxEvidence { licenses: evidence }) },
+    })
+}
+
+/// The license texts found for a package, chosen in the same way as for the
+/// other bundle variants
+fn cyclonedx_evidence(context: &mut Context, package: &Package, license: &License) -> CargoResult<Vec<CycloneDxLicenseChoice>> {
+    fn evidence(license: &License, text: &str) -> CycloneDxLicenseChoice {
+        let (id, name) = match *license {
+            License::Spdx(license) => (Some(license.id.to_owned()), None),
+            ref license => (None, Some(license.to_string())),
+        };
+        CycloneDxLicenseChoice::License(CycloneDxLicense {
+            id,
+            name,
+            text: Some(CycloneDxText {
+                content_type: "text/plain",
+                encoding: "base64",
+                content: base64(text.as_bytes()),
+            }),
+        })
+    }
+
+    let mut licenses = Vec::new();
+    if let Some(text) = generic_license_text(context, package, license)? {
+        report_confidence(context, package, license, &text)?;
+        let text = with_exceptions(context, package, license, text.text)?;
+        licenses.push(evidence(license, &text));
+    } else if let License::Unspecified = *license {
+        context.shell.error(format_args!("{} does not specify a license", package.name()))?;
     } else {
-        match license {
-            License::Unspecified => {
-                context.shell.error(format_args!("{} does not specify a license", package.name()))?;
-            }
-            License::Multiple(licenses) => {
-                let mut first = true;
-                for license in licenses {
-                    if first {
-                        first = false;
-                    } else {
-                        writeln!(out)?;
-                        writeln!(out, "    ===============")?;
-                        writeln!(out)?;
+        for license in license.simple_licenses() {
+            let texts = context.discovery.find_license_text(package, license)?;
+            if let Some(text) = choose(context, package, license, texts)? {
+                let text = with_exceptions(context, package, license, text.text)?;
+                licenses.push(evidence(license, &text));
+            }
+        }
+    }
+    Ok(licenses)
+}
+
+fn cyclonedx_xml(bom: &CycloneDxBom, out: &mut io::Write) -> CargoResult<()> {
+    fn component(component: &CycloneDxComponent, indent: &str, out: &mut io::Write) -> CargoResult<()> {
+        writeln!(out, "{}<component type=\"{}\" bom-ref=\"{}\">", indent, component.kind, xml_escape(&component.bom_ref))?;
+        writeln!(out, "{}  <name>{}</name>", indent, xml_escape(&component.name))?;
+        writeln!(out, "{}  <version>{}</version>", indent, xml_escape(&component.version))?;
+        if let Some(scope) = component.scope {
+            writeln!(out, "{}  <scope>{}</scope>", indent, scope)?;
+        }
+        if !component.licenses.is_empty() {
+            writeln!(out, "{}  <licenses>", indent)?;
+            licenses(&component.licenses, &format!("{}    ", indent), out)?;
+            writeln!(out, "{}  </licenses>", indent)?;
+        }
+        writeln!(out, "{}  <purl>{}</purl>", indent, xml_escape(&component.purl))?;
+        if let Some(ref evidence) = component.evidence {
+            writeln!(out, "{}  <evidence>", indent)?;
+            writeln!(out, "{}    <licenses>", indent)?;
+            licenses(&evidence.licenses, &format!("{}      ", indent), out)?;
+            writeln!(out, "{}    </licenses>", indent)?;
+            writeln!(out, "{}  </evidence>", indent)?;
+        }
+        writeln!(out, "{}</component>", indent)?;
+        Ok(())
+    }
+
+    fn licenses(licenses: &[CycloneDxLicenseChoice], indent: &str, out: &mut io::Write) -> CargoResult<()> {
+        for license in licenses {
+            match *license {
+                CycloneDxLicenseChoice::Expression(ref expression) => {
+                    writeln!(out, "{}<expression>{}</expression>", indent, xml_escape(expression))?;
+                }
+                CycloneDxLicenseChoice::License(ref license) => {
+                    writeln!(out, "{}<license>", indent)?;
+                    if let Some(ref id) = license.id {
+                        writeln!(out, "{}  <id>{}</id>", indent, xml_escape(id))?;
+                    }
+                    if let Some(ref name) = license.name {
+                        writeln!(out, "{}  <name>{}</name>", indent, xml_escape(name))?;
+                    }
+                    if let Some(ref text) = license.text {
+                        writeln!(out, "{}  <text content-type=\"{}\" encoding=\"{}\">{}</text>", indent, text.content_type, text.encoding, text.content)?;
                     }
-                    inline_license(context, package, &license, out)?;
+                    writeln!(out, "{}</license>", indent)?;
                 }
             }
-            license => {
-                inline_license(context, package, &license, out)?;
+        }
+        Ok(())
+    }
+
+    writeln!(out, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(out, "<bom xmlns=\"http://cyclonedx.org/schema/bom/{}\" serialNumber=\"{}\" version=\"{}\">", bom.spec_version, bom.serial_number, bom.version)?;
+    writeln!(out, "  <metadata>")?;
+    writeln!(out, "    <timestamp>{}</timestamp>", bom.metadata.timestamp)?;
+    writeln!(out, "    <tools>")?;
+    writeln!(out, "      <components>")?;
+    for tool in &bom.metadata.tools.components {
+        writeln!(out, "        <component type=\"{}\">", tool.kind)?;
+        writeln!(out, "          <name>{}</name>", tool.name)?;
+        writeln!(out, "          <version>{}</version>", tool.version)?;
+        writeln!(out, "        </component>")?;
+    }
+    writeln!(out, "      </components>")?;
+    writeln!(out, "    </tools>")?;
+    if let Some(ref root) = bom.metadata.component {
+        component(root, "    ", out)?;
+    }
+    writeln!(out, "  </metadata>")?;
+    writeln!(out, "  <components>")?;
+    for c in &bom.components {
+        component(c, "    ", out)?;
+    }
+    writeln!(out, "  </components>")?;
+    writeln!(out, "  <dependencies>")?;
+    for dependency in &bom.dependencies {
+        if dependency.depends_on.is_empty() {
+            writeln!(out, "    <dependency ref=\"{}\"/>", xml_escape(&dependency.reference))?;
+        } else {
+            writeln!(out, "    <dependency ref=\"{}\">", xml_escape(&dependency.reference))?;
+            for dep in &dependency.depends_on {
+                writeln!(out, "      <dependency ref=\"{}\"/>", xml_escape(dep))?;
             }
+            writeln!(out, "    </dependency>")?;
         }
     }
-    writeln!(out)?;
+    writeln!(out, "  </dependencies>")?;
+    writeln!(out, "</bom>")?;
     Ok(())
 }
 
-fn source_package(context: &mut Context, package: &Package, out: &mut io::Write) -> CargoResult<()> {
-    let license = package.license();
-    if let Some(text) = find_generic_license_text(package, &license)? {
-        match text.confidence {
-            Confidence::Confident => (),
-            Confidence::SemiConfident => {
-                context.shell.warn(format_args!("{} has only a low-confidence candidate for license {}:", package.name(), license))?;
-                context.shell.warn(format_args!("    {}", text.path.display()))?;
-            }
-            Confidence::Unsure => {
-                context.shell.error(format_args!("{} has only a very low-confidence candidate for license {}:", package.name(), license))?;
-                context.shell.error(format_args!("    {}", text.path.display()))?;
+/// A package URL, see https://github.com/package-url/purl-spec
+fn purl(package: &Package) -> String {
+    let source_id = package.package_id().source_id();
+    let mut purl = format!("pkg:cargo/{}@{}", package.name(), package.version().to_string().replace('+', "%2B"));
+    if source_id.is_git() {
+        purl += "?vcs_url=git%2B";
+        purl += &source_id.url().to_string().replace(':', "%3A");
+        if let Some(rev) = source_id.precise() {
+            purl += "%40";
+            purl += rev;
+        }
+    } else if source_id.is_registry() && !source_id.is_default_registry() {
+        purl += "?repository_url=";
+        purl += &source_id.url().to_string().replace(':', "%3A");
+    }
+    purl
+}
+
+/// A reference to a package that is unique within the bill of materials, its
+/// purl unless that doesn't say where the package came from
+fn bom_ref(package: &Package, purl: &str) -> String {
+    let source_id = package.package_id().source_id();
+    if source_id.is_path() {
+        format!("{}#{}", purl, source_id.url())
+    } else {
+        purl.to_owned()
+    }
+}
+
+/// Whether every part of a simple license is on the SPDX list
+fn is_spdx(license: &License) -> bool {
+    match *license {
+        License::Spdx(_) => true,
+        License::With(ref base, Exception::Spdx(_)) => is_spdx(base),
+        _ => false,
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        encoded.push(ALPHABET[(n >> 18) as usize & 63] as char);
+        encoded.push(ALPHABET[(n >> 12) as usize & 63] as char);
+        encoded.push(if chunk.len() > 1 { ALPHABET[(n >> 6) as usize & 63] as char } else { '=' });
+        encoded.push(if chunk.len() > 2 { ALPHABET[n as usize & 63] as char } else { '=' });
+    }
+    encoded
+}
+
+/// What part of a package's licensing a section is
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum SectionKind {
+    License,
+    Notice,
+    Vendored,
+}
+
+/// A text that has to be distributed along with a package, `name` is the
+/// license or the path of the file it came from
+struct Section {
+    kind: SectionKind,
+    name: String,
+    /// `None` for a license we couldn't find the text of
+    text: Option<String>,
+}
+
+impl Section {
+    fn title(&self) -> String {
+        match self.kind {
+            SectionKind::License => self.name.clone(),
+            SectionKind::Notice => format!("NOTICE ({})", self.name),
+            SectionKind::Vendored => format!("Vendored in {}", self.name),
+        }
+    }
+}
+
+/// The texts for every package, with identical texts shared between them, as
+/// the indices into the texts used by each package
+fn shared_texts(context: &mut Context) -> CargoResult<(Vec<Vec<usize>>, Vec<Section>)> {
+    let mut texts: Vec<Section> = Vec::new();
+    let mut package_texts = Vec::new();
+    for package in context.packages {
+        let mut indices = Vec::new();
+        for section in package_sections(context, package)? {
+            let index = match section.text {
+                Some(ref text) => texts.iter().position(|shared| shared.text.as_ref().map(|shared| shared.trim()) == Some(text.trim())),
+                None => continue,
+            };
+            let index = match index {
+                Some(index) => index,
+                None => {
+                    texts.push(section);
+                    texts.len() - 1
+                }
+            };
+            if !indices.contains(&index) {
+                indices.push(index);
             }
         }
-        writeln!(out, "
-    LicensedCrate {{
-        name: {:?},
-        version: {:?},
-        licenses: Licenses {{
-            name: {:?},
-            licenses: &[
-                License {{
-                    name: {:?},
-                    text: Some({:?}),
-                }},
-            ],
-        }},
-    }},", package.name(), package.version().to_string(), license.to_string(), license.to_string(), text.text)?;
+        package_texts.push(indices);
+    }
+    Ok((package_texts, texts))
+}
+
+/// Every text that has to be distributed with a package, its licenses, its
+/// NOTICE and the licenses of anything vendored into it
+fn package_sections(context: &mut Context, package: &Package) -> CargoResult<Vec<Section>> {
+    let license = context.licenses.license(package);
+    let mut sections = Vec::new();
+    if let Some(text) = generic_license_text(context, package, &license)? {
+        report_confidence(context, package, &license, &text)?;
+        let text = with_exceptions(context, package, &license, text.text)?;
+        sections.push(Section { kind: SectionKind::License, name: license.to_string(), text: Some(text) });
+    } else if let License::Unspecified = license {
+        context.shell.error(format_args!("{} does not specify a license", package.name()))?;
     } else {
-        let license_name = license.to_string();
-        match license {
-            License::Unspecified => {
-                context.shell.error(format_args!("{} does not specify a license", package.name()))?;
+        for license in license.simple_licenses() {
+            let texts = context.discovery.find_license_text(package, license)?;
+            let text = match choose(context, package, license, texts)? {
+                Some(text) => Some(with_exceptions(context, package, license, text.text)?),
+                None => None,
+            };
+            sections.push(Section { kind: SectionKind::License, name: license.to_string(), text });
+        }
+    }
+    if let Some(notice) = notice(context, package)? {
+        sections.push(Section { kind: SectionKind::Notice, name: notice.path.display().to_string(), text: Some(notice.text) });
+    }
+    for file in context.discovery.find_vendored_license_texts(package)? {
+        sections.push(Section { kind: SectionKind::Vendored, name: file.path.display().to_string(), text: Some(file.text) });
+    }
+    Ok(sections)
+}
+
+fn inline_package(context: &mut Context, package: &Package, out: &mut io::Write) -> CargoResult<()> {
+    let mut first = true;
+    for section in package_sections(context, package)? {
+        let text = section.text.as_ref().map_or("", |text| &text[..]);
+        match section.kind {
+            SectionKind::License => {
+                if first {
+                    first = false;
+                } else {
+                    writeln!(out)?;
+                    writeln!(out, "    ===============")?;
+                    writeln!(out)?;
+                }
+                for line in text.lines() {
+                    writeln!(out, "    {}", line)?;
+                }
             }
-            License::Multiple(licenses) => {
-                writeln!(out, "
-    LicensedCrate {{
-        name: {:?},
-        version: {:?},
-        licenses: Licenses {{
-            name: {:?},
-            licenses: &[", package.name(), package.version().to_string(), license_name)?;
-                for license in licenses {
-                    let texts = find_license_text(package, &license)?;
-                    let text = (choose(context, package, &license, texts)?).map(|t| format!("Some({:?})", t.text)).unwrap_or_else(|| "None".to_owned());
-                    writeln!(out, "
-                License {{
-                    name: {:?},
-                    text: {},
-                }},", license.to_string(), text)?;
+            SectionKind::Notice | SectionKind::Vendored => {
+                writeln!(out)?;
+                writeln!(out, "    {}:", section.title())?;
+                writeln!(out)?;
+                for line in text.lines() {
+                    writeln!(out, "        {}", line)?;
                 }
-                writeln!(out, "
-            ],
-        }},
-    }},")?;
             }
-            license => {
-                let texts = find_license_text(package, &license)?;
-                let text = (choose(context, package, &license, texts)?).map(|t| format!("Some({:?})", t.text)).unwrap_or_else(|| "None".to_owned());
-                writeln!(out, "
+        }
+    }
+    writeln!(out)?;
+    Ok(())
+}
+
+/// The licenses of any vendored sub-components are added to the package's
+/// list, named by their path in the package
+fn source_package(context: &mut Context, package: &Package, out: &mut io::Write) -> CargoResult<()> {
+    let license = context.licenses.license(package);
+    let sections = package_sections(context, package)?;
+    if sections.iter().any(|section| section.kind == SectionKind::License) {
+        writeln!(out, "
     LicensedCrate {{
         name: {:?},
         version: {:?},
         licenses: Licenses {{
             name: {:?},
-            licenses: &[
+            licenses: &[", package.name(), package.version().to_string(), license.to_string())?;
+        let mut notice = "None".to_owned();
+        for section in sections {
+            let text = match section.text {
+                Some(text) => format!("Some({:?})", text),
+                None => "None".to_owned(),
+            };
+            if section.kind == SectionKind::Notice {
+                notice = text;
+                continue;
+            }
+            writeln!(out, "
                 License {{
                     name: {:?},
                     text: {},
-                }},
+                }},", section.name, text)?;
+        }
+        writeln!(out, "
             ],
         }},
-    }},", package.name(), package.version().to_string(), license.to_string(), license.to_string(), text)?;
-            }
-        }
+        notice: {},
+    }},", notice)?;
     }
     writeln!(out)?;
     Ok(())
 }
 
 fn split_package(context: &mut Context, package: &Package, dir: &Path) -> CargoResult<()> {
-    let license = package.license();
     let mut file = File::create(dir.join(package.name().as_str()))?;
-    if let Some(text) = find_generic_license_text(package, &license)? {
-        match text.confidence {
-            Confidence::Confident => (),
-            Confidence::SemiConfident => {
-                context.shell.warn(format_args!("{} has only a low-confidence candidate for license {}:", package.name(), license))?;
-                context.shell.warn(format_args!("    {}", text.path.display()))?;
+    let mut first = true;
+    for section in package_sections(context, package)? {
+        let title = section.title();
+        let text = section.text.unwrap_or_default();
+        match section.kind {
+            SectionKind::License => {
+                if first {
+                    first = false;
+                } else {
+                    writeln!(file)?;
+                    writeln!(file, "===============")?;
+                    writeln!(file)?;
+                }
+                file.write_all(text.as_bytes())?;
+            }
+            SectionKind::Notice => {
+                File::create(dir.join(format!("{}.NOTICE", package.name())))?.write_all(text.as_bytes())?;
             }
-            Confidence::Unsure => {
-                context.shell.error(format_args!("{} has only a very low-confidence candidate for license {}:", package.name(), license))?;
-                context.shell.error(format_args!("    {}", text.path.display()))?;
+            SectionKind::Vendored => {
+                writeln!(file)?;
+                writeln!(file, "===============")?;
+                writeln!(file)?;
+                writeln!(file, "{}:", title)?;
+                writeln!(file)?;
+                file.write_all(text.as_bytes())?;
             }
         }
-        file.write_all(text.text.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Notes packages that are only used to build or test the roots
+fn usage(context: &Context, package: &Package) -> &'static str {
+    let kinds = context.graph.kinds(package.package_id());
+    if kinds.contains(&Kind::Normal) {
+        ""
+    } else if kinds.contains(&Kind::Build) && kinds.contains(&Kind::Development) {
+        " (build and dev dependency)"
+    } else if kinds.contains(&Kind::Build) {
+        " (build dependency)"
     } else {
-        match license {
-            License::Unspecified => {
-                context.shell.error(format_args!("{} does not specify a license", package.name()))?;
-            }
-            License::Multiple(licenses) => {
-                let mut first = true;
-                for license in licenses {
-                    if first {
-                        first = false;
-                    } else {
-                        writeln!(file)?;
-                        writeln!(file, "===============")?;
-                        writeln!(file)?;
-                    }
-                    let texts = find_license_text(package, &license)?;
-                    if let Some(text) = choose(context, package, &license, texts)? {
-                        file.write_all(text.text.as_bytes())?;
-                    }
-                }
-            }
-            license => {
-                let texts = find_license_text(package, &license)?;
-                if let Some(text) = choose(context, package, &license, texts)? {
-                    file.write_all(text.text.as_bytes())?;
+        " (dev dependency)"
+    }
+}
+
+/// The `NOTICE` file of a package, flagging Apache-2.0 packages that refer to
+/// one without shipping it
+fn notice(context: &mut Context, package: &Package) -> CargoResult<Option<LicenseFile>> {
+    let notice = context.discovery.find_notice(package)?;
+    if notice.is_none() && is_apache(&context.licenses.license(package)) && context.discovery.references_notice(package)? {
+        context.shell.warn(format_args!("{} refers to a NOTICE file but there is none in {}", package.name(), package.root().display()))?;
+    }
+    Ok(notice)
+}
+
+fn is_apache(license: &License) -> bool {
+    license.simple_licenses().into_iter().any(|license| match *license {
+        License::Spdx(license) => license.id == "Apache-2.0",
+        License::With(ref base, _) => is_apache(base),
+        _ => false,
+    })
+}
+
+/// Files named by a clarification in `lichking.toml` are used as is, in place
+/// of any we could discover
+fn generic_license_text(context: &Context, package: &Package, license: &License) -> CargoResult<Option<LicenseText>> {
+    if let Some(texts) = context.licenses.texts(package) {
+        return Ok(Some(LicenseText {
+            path: texts[0].0.clone(),
+            text: texts.iter().map(|&(_, ref text)| &text[..]).collect::<Vec<_>>().join("\n===============\n\n"),
+            score: None,
+            confidence: Confidence::Confident,
+            variables: BTreeMap::new(),
+        }));
+    }
+    context.discovery.find_generic_license_text(package, license)
+}
+
+/// Appends the text of any license exceptions that apply to `license` and
+/// aren't already included in the package's own license text
+fn with_exceptions(context: &mut Context, package: &Package, license: &License, mut text: String) -> CargoResult<String> {
+    fn normalize(text: &str) -> String {
+        text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+    }
+
+    for license in license.simple_licenses() {
+        if let License::With(_, ref exception) = *license {
+            if let Some(template) = exception.template() {
+                let template = context.discovery.template(template).text();
+                let first_line = template.lines().find(|line| !line.trim().is_empty()).unwrap_or("");
+                if !normalize(&text).contains(&normalize(first_line)) {
+                    text.push_str("\n");
+                    text.push_str(&template);
                 }
+            } else {
+                context.shell.warn(format_args!("{} uses exception {} which our liches don't have the text of", package.name(), exception))?;
             }
         }
     }
-    Ok(())
+    Ok(text)
+}
+
+/// The standard text of a license with the package's copyright notices, or
+/// failing those its authors, filled in for packages that don't include the
+/// text themselves, only with `--fallback-to-template`
+fn synthesise(context: &Context, package: &Package, license: &License) -> CargoResult<Option<LicenseText>> {
+    let template = match license.template() {
+        Some(template) if context.fallback_to_template => context.discovery.template(template),
+        _ => return Ok(None),
+    };
+    let copyrights = context.discovery.find_copyrights(package, license)?;
+    let notices = copyrights.iter().map(|copyright| &copyright.notice[..]).collect::<Vec<_>>().join("\n");
+    let (text, variables) = fill_notices(&template, &notices);
+    let text = format!("\
+This text was synthesised by cargo-lichking from the standard text of {}, it
+was not included in {} {}.
+
+{}", license, package.name(), package.version(), text);
+    Ok(Some(LicenseText {
+        path: PathBuf::new(),
+        text,
+        score: None,
+        confidence: Confidence::Confident,
+        variables,
+    }))
 }
 
-fn inline_license(context: &mut Context, package: &Package, license: &License, out: &mut io::Write) -> CargoResult<()> {
-    let texts = find_license_text(package, license)?;
-    if let Some(text) = choose(context, package, license, texts)? {
-        for line in text.text.lines() {
-            writeln!(out, "    {}", line)?;
+/// The standard text of a license with copyright notices in place of the
+/// template's, or above the terms for templates that only have a place for
+/// them in an optional appendix explaining how to apply the license, which is
+/// left out
+fn fill_notices(template: &Template, notices: &str) -> (String, BTreeMap<String, String>) {
+    let mut variables = BTreeMap::new();
+    if notices.is_empty() {
+        (template.text(), variables)
+    } else if template.is_required("copyright") {
+        variables.insert("copyright".to_owned(), notices.to_owned());
+        (template.fill(&variables), variables)
+    } else {
+        (format!("{}\n\n{}", notices, template.fill_required(&variables)), variables)
+    }
+}
+
+/// Warns about a license text that we aren't confident is for `license`
+fn report_confidence(context: &mut Context, package: &Package, license: &License, text: &LicenseText) -> CargoResult<()> {
+    match text.confidence {
+        Confidence::Confident => (),
+        Confidence::SemiConfident => {
+            context.shell.warn(format_args!("{} has only a low-confidence candidate for license {}:", package.name(), license))?;
+            context.shell.warn(format_args!("    {}", candidate(text)))?;
+        }
+        Confidence::Unsure => {
+            context.shell.error(format_args!("{} has only a very low-confidence candidate for license {}:", package.name(), license))?;
+            context.shell.error(format_args!("    {}", candidate(text)))?;
         }
     }
     Ok(())
 }
 
+/// A candidate license text's path, with how closely it matched
+fn candidate(text: &LicenseText) -> String {
+    match text.score {
+        Some(score) => format!("{} ({:.0}% match)", text.path.display(), score * 100.0),
+        None => text.path.display().to_string(),
+    }
+}
+
 fn choose(context: &mut Context, package: &Package, license: &License, texts: Vec<LicenseText>) -> CargoResult<Option<LicenseText>> {
     let (mut confident, texts): (Vec<LicenseText>, Vec<LicenseText>) = texts.into_iter().partition(|text| text.confidence == Confidence::Confident);
     let (mut semi_confident, mut unconfident): (Vec<LicenseText>, Vec<LicenseText>) = texts.into_iter().partition(|text| text.confidence == Confidence::SemiConfident);
@@ -363,36 +1443,136 @@ fn choose(context: &mut Context, package: &Package, license: &License, texts: Ve
         } else if confident.len() > 1 {
             context.shell.error(format_args!("{} has multiple candidates for license {}:", package.name(), license))?;
             for text in &confident {
-                context.shell.error(format_args!("    {}", text.path.display()))?;
+                context.shell.error(format_args!("    {}", candidate(text)))?;
             }
             confident.swap_remove(0)
         } else if semi_confident.len() == 1 {
             context.shell.warn(format_args!("{} has only a low-confidence candidate for license {}:", package.name(), license))?;
-            context.shell.warn(format_args!("    {}", semi_confident[0].path.display()))?;
+            context.shell.warn(format_args!("    {}", candidate(&semi_confident[0])))?;
             semi_confident.swap_remove(0)
         } else if semi_confident.len() > 1 {
             context.low_quality_license = true;
             context.shell.error(format_args!("{} has multiple low-confidence candidates for license {}:", package.name(), license))?;
             for text in &semi_confident {
-                context.shell.error(format_args!("    {}", text.path.display()))?;
+                context.shell.error(format_args!("    {}", candidate(text)))?;
             }
             semi_confident.swap_remove(0)
         } else if unconfident.len() == 1 {
             context.low_quality_license = true;
             context.shell.warn(format_args!("{} has only a very low-confidence candidate for license {}:", package.name(), license))?;
-            context.shell.warn(format_args!("    {}", unconfident[0].path.display()))?;
+            context.shell.warn(format_args!("    {}", candidate(&unconfident[0])))?;
             unconfident.swap_remove(0)
         } else if unconfident.len() > 1 {
             context.low_quality_license = true;
             context.shell.error(format_args!("{} has multiple very low-confidence candidates for license {}:", package.name(), license))?;
             for text in &unconfident {
-                context.shell.error(format_args!("    {}", text.path.display()))?;
+                context.shell.error(format_args!("    {}", candidate(text)))?;
             }
             unconfident.swap_remove(0)
+        } else if let Some(text) = synthesise(context, package, license)? {
+            context.shell.warn(format_args!("{} has no candidate texts for license {} in {}, using the standard text", package.name(), license, package.root().display()))?;
+            text
         } else {
             context.shell.error(format_args!("{} has no candidate texts for license {} in {}", package.name(), license, package.root().display()))?;
+            if license.template().is_some() {
+                context.shell.error(format_args!("    pass --fallback-to-template to use the standard text of {}", license))?;
+            }
             context.missing_license = true;
             return Ok(None);
         }
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use template::Parser;
+
+    use super::{base64, fill_notices, html_pre, iso8601, markdown_code_block, markdown_escape, xml_escape};
+
+    const NOTICES: &str = "Copyright (c) 2016 Jane Doe\nCopyright (c) 2018 John Doe";
+
+    #[test]
+    fn notices_fill_the_template() {
+        let template = Parser::new().template(include_str!("licenses/MIT"));
+        let (text, variables) = fill_notices(&template, NOTICES);
+        assert!(text.starts_with("MIT License\n\nCopyright (c) 2016 Jane Doe\nCopyright (c) 2018 John Doe\n\nPermission is hereby granted"));
+        assert!(!text.contains("<YEAR>"));
+        assert_eq!(variables.get("copyright").map(|s| &s[..]), Some(NOTICES));
+    }
+
+    #[test]
+    fn notices_go_above_terms_instead_of_appendix() {
+        let parser = Parser::new();
+        for &(source, end) in &[
+            (include_str!("licenses/Apache-2.0"), "END OF TERMS AND CONDITIONS"),
+            (include_str!("licenses/GPL-3.0"), "END OF TERMS AND CONDITIONS"),
+        ] {
+            let template = parser.template(source);
+            let (text, variables) = fill_notices(&template, NOTICES);
+            assert!(text.starts_with(&format!("{}\n\n", NOTICES)));
+            assert!(text.trim_end().ends_with(end), "{}", &text[text.len() - 200..]);
+            assert!(!text.contains("[yyyy]") && !text.contains("<year>"));
+            assert!(variables.is_empty());
+        }
+    }
+
+    #[test]
+    fn no_notices_keep_the_standard_text() {
+        let template = Parser::new().template(include_str!("licenses/Apache-2.0"));
+        assert_eq!(fill_notices(&template, "").0, template.text());
+    }
+
+    #[test]
+    fn markdown_escapes_inline_text() {
+        assert_eq!(markdown_escape("foo_bar"), r"foo\_bar");
+        assert_eq!(markdown_escape("a<b> & `c` | *d* [e](f) #g"), r"a\<b\> \& \`c\` \| \*d\* \[e\](f) \#g");
+        assert_eq!(markdown_escape("&lt;"), r"\&lt;");
+    }
+
+    #[test]
+    fn markdown_code_blocks_are_verbatim() {
+        assert_eq!(markdown_code_block("a < b && `c`\n\n"), "```text\na < b && `c`\n```\n");
+    }
+
+    #[test]
+    fn markdown_fences_are_longer_than_the_text_has() {
+        let text = "Example:\n\n```rust\nfn main() {}\n```\n\nand ````";
+        let block = markdown_code_block(text);
+        assert_eq!(block, format!("`````text\n{}\n`````\n", text));
+    }
+
+    #[test]
+    fn html_texts_are_escaped() {
+        assert_eq!(html_pre("a < b && c > d\n\n"), "<pre>a &lt; b &amp;&amp; c &gt; d</pre>");
+        assert_eq!(html_pre("</pre><script>alert(1)</script>"), "<pre>&lt;/pre&gt;&lt;script&gt;alert(1)&lt;/script&gt;</pre>");
+        assert_eq!(html_pre("```\n`code`\n```"), "<pre>```\n`code`\n```</pre>");
+        assert_eq!(html_pre("&lt;"), "<pre>&amp;lt;</pre>");
+    }
+
+    #[test]
+    fn html_attributes_are_escaped() {
+        assert_eq!(xml_escape(r#"https://example.com/?a=1&b="2"'"#), "https://example.com/?a=1&amp;b=&quot;2&quot;&apos;");
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(iso8601(0), "1970-01-01T00:00:00Z");
+        assert_eq!(iso8601(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(iso8601(1_234_567_890), "2009-02-13T23:31:30Z");
+        assert_eq!(iso8601(1_709_251_199), "2024-02-29T23:59:59Z");
+        assert_eq!(iso8601(4_107_542_400), "2100-03-01T00:00:00Z");
+    }
+
+    #[test]
+    fn base64_padding() {
+        // The test vectors from RFC 4648
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foob"), "Zm9vYg==");
+        assert_eq!(base64(b"fooba"), "Zm9vYmE=");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64(&[0, 255, 254, 1]), "AP/+AQ==");
+    }
+}
@@ -1,22 +1,49 @@
 use cargo::core::Package;
+use cargo::core::dependency::Kind;
 use cargo::{ Config, CargoResult };
 
-use licensed::Licensed;
+use compatibility::Matrix;
+use licensed::Licenses;
+use load::Graph;
+use policy::{Decision, Policy};
+use settings::{self, Settings, Treatment};
+use why;
 
-pub fn run(root: &Package, packages: Vec<Package>, config: &Config) -> CargoResult<()> {
+/// What checking a single package reported
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Outcome {
+    Ok,
+    Warning,
+    Error,
+}
+
+pub fn run(root: &Package, graph: &Graph, config: &Config, settings: &Settings, licenses: &Licenses, explain: bool) -> CargoResult<()> {
     let mut fail = 0;
-    let license = root.license();
-
-    for package in packages {
-        if &package == root { continue }
-        let can_include = license.can_include(&package.license());
-        if let Some(can_include) = can_include {
-            if !can_include {
-                config.shell().error(format!("{} cannot include package {}, license {} is incompatible with {}", root.name(), package.name(), package.license(), license))?;
-                fail += 1;
-            }
+
+    for package in &graph.packages {
+        if package == root { continue }
+        // Dependencies only used to test the root don't end up in it, so by
+        // default problems with them are only warnings
+        let kinds = graph.kinds(package.package_id());
+        let unlinked = match settings.treatment(kinds) {
+            Treatment::Check => None,
+            Treatment::Warn => Some(describe_kinds(kinds)),
+            Treatment::Ignore => continue,
+        };
+        // An explicit policy replaces checking against the root's license
+        let mut outcome = if let Some(ref policy) = settings.policy {
+            check_policy(root, package, config, policy, licenses, unlinked)?
         } else {
-            config.shell().warn(format!("{} might not be able to include package {}, license {} is not known to be compatible with {}", root.name(), package.name(), package.license(), license))?;
+            check_compatibility(root, package, config, &settings.compatibility, licenses, unlinked)?
+        };
+        if !check_license_texts(package, config, settings, licenses)? && outcome == Outcome::Ok {
+            outcome = Outcome::Warning;
+        }
+        if explain && outcome != Outcome::Ok {
+            why::explain(config, graph, package.package_id(), outcome == Outcome::Error)?;
+        }
+        if outcome == Outcome::Error {
+            fail += 1;
         }
     }
 
@@ -26,3 +53,79 @@ pub fn run(root: &Package, packages: Vec<Package>, config: &Config) -> CargoResu
         Ok(())
     }
 }
+
+fn describe_kinds(kinds: &[Kind]) -> String {
+    kinds.iter()
+        .map(|kind| match *kind {
+            Kind::Normal => "normal",
+            Kind::Build => "build",
+            Kind::Development => "dev",
+        })
+        .collect::<Vec<_>>()
+        .join(" and ")
+}
+
+/// Reports a package that cannot be included, for a package that isn't linked
+/// into the root this is only a warning
+fn incompatible(config: &Config, message: String, reasons: &[String], unlinked: Option<String>) -> CargoResult<Outcome> {
+    if let Some(kinds) = unlinked {
+        config.shell().warn(format!("{}, but it is only used as a {} dependency", message, kinds))?;
+        for reason in reasons {
+            config.shell().warn(format!("    {}", reason))?;
+        }
+        Ok(Outcome::Warning)
+    } else {
+        config.shell().error(message)?;
+        for reason in reasons {
+            config.shell().error(format!("    {}", reason))?;
+        }
+        Ok(Outcome::Error)
+    }
+}
+
+fn check_compatibility(root: &Package, package: &Package, config: &Config, matrix: &Matrix, licenses: &Licenses, unlinked: Option<String>) -> CargoResult<Outcome> {
+    let license = licenses.license(root);
+    let package_license = licenses.license(package);
+    let verdict = license.can_include(&package_license, matrix);
+    if let Some(can_include) = verdict.compatible {
+        if !can_include {
+            return incompatible(config, format!("{} cannot include package {}, license {} is incompatible with {}", root.name(), package.name(), package_license, license), &verdict.reasons, unlinked);
+        }
+    } else {
+        config.shell().warn(format!("{} might not be able to include package {}, license {} is not known to be compatible with {}", root.name(), package.name(), package_license, license))?;
+        for reason in &verdict.reasons {
+            config.shell().warn(format!("    {}", reason))?;
+        }
+        return Ok(Outcome::Warning);
+    }
+    Ok(Outcome::Ok)
+}
+
+fn check_policy(root: &Package, package: &Package, config: &Config, policy: &Policy, licenses: &Licenses, unlinked: Option<String>) -> CargoResult<Outcome> {
+    let package_license = licenses.license(package);
+    match policy.evaluate(&package_license) {
+        Decision::Allow => Ok(Outcome::Ok),
+        Decision::Warn => {
+            config.shell().warn(format!("{} includes package {}, license {} is on the warn list in {}", root.name(), package.name(), package_license, settings::FILE_NAME))?;
+            Ok(Outcome::Warning)
+        }
+        Decision::Review => {
+            config.shell().warn(format!("{} might not be able to include package {}, license {} is not covered by the policy in {} and needs review", root.name(), package.name(), package_license, settings::FILE_NAME))?;
+            Ok(Outcome::Warning)
+        }
+        Decision::Deny => {
+            incompatible(config, format!("{} cannot include package {}, license {} is denied by the policy in {}", root.name(), package.name(), package_license, settings::FILE_NAME), &[], unlinked)
+        }
+    }
+}
+
+/// Warns about license files that look like a different license to the one
+/// the package uses, returns whether there were none
+fn check_license_texts(package: &Package, config: &Config, settings: &Settings, licenses: &Licenses) -> CargoResult<bool> {
+    let license = licenses.license(package);
+    let mismatches = settings.discovery.find_mismatched_license_texts(package, &license)?;
+    for &(ref path, ref classification) in &mismatches {
+        config.shell().warn(format!("{} uses license {} but {} looks like {} ({:.0}% match)", package.name(), license, path.display(), classification.license, classification.score * 100.0))?;
+    }
+    Ok(mismatches.is_empty())
+}
@@ -0,0 +1,181 @@
+use std::path::PathBuf;
+
+use cargo::core::Package;
+use cargo::CargoResult;
+use crypto_hash::{self, Algorithm};
+use semver::{Version, VersionReq};
+use void::ResultVoidExt;
+
+use license::License;
+
+/// A `[[clarify]]` entry in a `lichking.toml`
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Entry {
+    name: String,
+    version: Option<String>,
+    expression: String,
+    #[serde(default)]
+    license_files: Vec<FileEntry>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileEntry {
+    path: PathBuf,
+    hash: Option<String>,
+}
+
+/// A license file named by a clarification, relative to the package root
+#[derive(Clone, Debug)]
+pub struct LicenseFile {
+    pub path: PathBuf,
+    /// Hex encoded SHA-256 of the expected file contents
+    pub hash: Option<String>,
+}
+
+/// How the contents of a license file compare to the hash pinned for it
+#[derive(Debug, Eq, PartialEq)]
+pub enum Hash {
+    Matches,
+    /// No hash is pinned, the file has the given one
+    Unpinned(String),
+    Changed { expected: String, found: String },
+}
+
+impl LicenseFile {
+    pub fn check(&self, text: &str) -> Hash {
+        let found = crypto_hash::hex_digest(Algorithm::SHA256, text.as_bytes());
+        match self.hash {
+            Some(ref expected) if *expected != found => Hash::Changed { expected: expected.clone(), found },
+            Some(_) => Hash::Matches,
+            None => Hash::Unpinned(found),
+        }
+    }
+}
+
+/// Overrides the license metadata of every version of a package matching
+/// `version`, for packages whose metadata is missing or wrong
+#[derive(Clone, Debug)]
+pub struct Clarification {
+    pub name: String,
+    pub version: VersionReq,
+    pub license: License,
+    pub license_files: Vec<LicenseFile>,
+}
+
+impl Clarification {
+    pub fn new(entry: Entry) -> CargoResult<Clarification> {
+        let version = match entry.version {
+            Some(ref version) => VersionReq::parse(version).map_err(|err| format_err!("invalid version '{}' in clarification for {}: {}", version, entry.name, err))?,
+            None => VersionReq::any(),
+        };
+        let license = entry.expression.parse::<License>().void_unwrap();
+
+        Ok(Clarification {
+            name: entry.name,
+            version,
+            license,
+            license_files: entry.license_files.into_iter().map(|file| LicenseFile {
+                path: file.path,
+                hash: file.hash.map(|hash| hash.to_lowercase()),
+            }).collect(),
+        })
+    }
+
+    pub fn matches(&self, package: &Package) -> bool {
+        self.applies_to(package.name().as_str(), package.version())
+    }
+
+    fn applies_to(&self, name: &str, version: &Version) -> bool {
+        name == self.name && self.version.matches(version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+    use toml;
+
+    use super::{Clarification, Hash};
+
+    // The SHA-256 of `MIT License\n`
+    const HASH: &str = "267f7a2e19dfa9df99af774520985a0e521925293ea5b7e767ab06969d06bf91";
+
+    fn clarification(entry: &str) -> Clarification {
+        Clarification::new(toml::from_str(entry).unwrap()).unwrap()
+    }
+
+    fn applies_to(clarification: &Clarification, name: &str, version: &str) -> bool {
+        clarification.applies_to(name, &Version::parse(version).unwrap())
+    }
+
+    #[test]
+    fn version_ranges() {
+        let caret = clarification(r#"name = "ring"
+            version = "^0.13"
+            expression = "MIT AND ISC AND OpenSSL""#);
+        assert!(applies_to(&caret, "ring", "0.13.0"));
+        assert!(applies_to(&caret, "ring", "0.13.5"));
+        assert!(!applies_to(&caret, "ring", "0.12.9"));
+        assert!(!applies_to(&caret, "ring", "0.14.0"));
+        assert!(!applies_to(&caret, "untrusted", "0.13.0"));
+
+        let range = clarification(r#"name = "ring"
+            version = ">= 1.2, < 2"
+            expression = "MIT""#);
+        assert!(applies_to(&range, "ring", "1.2.0"));
+        assert!(applies_to(&range, "ring", "1.9.3"));
+        assert!(!applies_to(&range, "ring", "1.1.0"));
+        assert!(!applies_to(&range, "ring", "2.0.0"));
+
+        let exact = clarification(r#"name = "ring"
+            version = "=0.13.2"
+            expression = "MIT""#);
+        assert!(applies_to(&exact, "ring", "0.13.2"));
+        assert!(!applies_to(&exact, "ring", "0.13.3"));
+    }
+
+    #[test]
+    fn every_version_without_a_range() {
+        let any = clarification(r#"name = "ring"
+            expression = "MIT""#);
+        assert!(applies_to(&any, "ring", "0.1.0"));
+        assert!(applies_to(&any, "ring", "100.0.0"));
+    }
+
+    #[test]
+    fn invalid_version_range() {
+        let entry = toml::from_str(r#"name = "ring"
+            version = "not a version"
+            expression = "MIT""#).unwrap();
+        assert!(Clarification::new(entry).is_err());
+    }
+
+    #[test]
+    fn hashes() {
+        let clarification = clarification(&format!(r#"name = "ring"
+            expression = "MIT"
+            license-files = [
+                {{ path = "LICENSE", hash = "{}" }},
+                {{ path = "COPYING" }},
+            ]"#, HASH.to_uppercase()));
+        let pinned = &clarification.license_files[0];
+        let unpinned = &clarification.license_files[1];
+        assert_eq!(pinned.hash.as_ref().map(|hash| &hash[..]), Some(HASH));
+
+        assert_eq!(pinned.check("MIT License\n"), Hash::Matches);
+        let found = match unpinned.check("MIT License\n") {
+            Hash::Unpinned(found) => found,
+            hash => panic!("expected an unpinned hash, found {:?}", hash),
+        };
+        assert_eq!(found, HASH);
+        match pinned.check("MIT License, modified\n") {
+            Hash::Changed { ref expected, ref found } => {
+                assert_eq!(expected, HASH);
+                assert_ne!(found, HASH);
+            }
+            hash => panic!("expected a changed hash, found {:?}", hash),
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::HashSet;
+
+use cargo::CargoResult;
+use toml;
+use void::ResultVoidExt;
+
+use license::License;
+use spdx::Category;
+
+const ANY: &str = "*";
+
+/// A single entry in the compatibility data, applies to every combination of
+/// `outer` and `inner` licenses
+#[derive(Clone, Debug, Deserialize)]
+pub struct Rule {
+    outer: Vec<String>,
+    inner: Vec<String>,
+    compatible: Option<bool>,
+    reason: String,
+}
+
+#[derive(Deserialize)]
+struct Rules {
+    rule: Vec<Rule>,
+}
+
+/// Whether code under one license can include code under another
+#[derive(Clone, Debug)]
+pub struct Verdict {
+    pub compatible: Option<bool>,
+    pub reasons: Vec<String>,
+}
+
+pub struct Matrix {
+    rules: Vec<Rule>,
+    known: HashSet<String>,
+}
+
+impl Matrix {
+    /// The compatibility data shipped with cargo-lichking
+    pub fn bundled() -> CargoResult<Matrix> {
+        let rules: Rules = toml::from_str(include_str!("compatibility.toml"))?;
+        let mut matrix = Matrix {
+            rules: Vec::new(),
+            known: HashSet::new(),
+        };
+        matrix.extend(rules.rule);
+        Ok(matrix)
+    }
+
+    /// Adds more rules, overriding any earlier rules that cover the same
+    /// combinations, including through `*`
+    pub fn extend<I: IntoIterator<Item=Rule>>(&mut self, rules: I) {
+        for mut rule in rules {
+            rule.outer = rule.outer.iter().map(|id| normalize(id)).collect();
+            rule.inner = rule.inner.iter().map(|id| normalize(id)).collect();
+            self.known.extend(rule.outer.iter().chain(&rule.inner).filter(|id| *id != ANY).cloned());
+            self.rules.push(rule);
+        }
+    }
+
+    /// The last rule covering a pair of licenses
+    fn rule(&self, outer: &str, inner: &str) -> Option<&Rule> {
+        let covers = |ids: &[String], id: &str| ids.iter().any(|candidate| candidate == id || candidate == ANY);
+        self.rules.iter().rev().find(|rule| covers(&rule.outer, outer) && covers(&rule.inner, inner))
+    }
+
+    /// Looks up a pair of simple licenses by their SPDX identifiers, the
+    /// categories are used as a fallback for licenses we have no data about
+    pub fn check(&self, outer: &str, outer_category: Option<Category>, inner: &str, inner_category: Option<Category>) -> Verdict {
+        if let Some(rule) = self.rule(outer, inner) {
+            return Verdict::new(outer, inner, rule.compatible, &rule.reason);
+        }
+
+        if self.known.contains(outer) && self.known.contains(inner) {
+            return Verdict::new(outer, inner, Some(false), "no rule allows this combination");
+        }
+
+        match (outer_category, inner_category) {
+            (Some(Category::Other), _) | (None, _) => {
+                Verdict::new(outer, inner, None, &format!("there is no information about {}", outer))
+            }
+            (_, Some(Category::Other)) | (_, None) => {
+                Verdict::new(outer, inner, None, &format!("there is no information about {}", inner))
+            }
+            (_, Some(Category::PublicDomain)) | (_, Some(Category::Permissive)) => {
+                Verdict::new(outer, inner, Some(true), &format!("{} looks like a permissive license", inner))
+            }
+            (Some(Category::PublicDomain), _) | (Some(Category::Permissive), _) => {
+                Verdict::new(outer, inner, Some(false), &format!("{} looks like a permissive license but {} has copyleft terms", outer, inner))
+            }
+            _ => {
+                Verdict::new(outer, inner, None, &format!("both {} and {} have copyleft terms", outer, inner))
+            }
+        }
+    }
+}
+
+/// Normalizes an identifier the same way they are when parsing a license, so
+/// that e.g. `GPL-2.0` and `GPL-2.0+` match `GPL-2.0-only` and
+/// `GPL-2.0-or-later`
+fn normalize(id: &str) -> String {
+    if id == ANY {
+        return id.to_owned();
+    }
+    match id.parse::<License>().void_unwrap() {
+        License::Spdx(license) => license.id.to_owned(),
+        _ => id.to_owned(),
+    }
+}
+
+impl Verdict {
+    pub fn new(outer: &str, inner: &str, compatible: Option<bool>, reason: &str) -> Verdict {
+        let relation = match compatible {
+            Some(true) => "can include",
+            Some(false) => "cannot include",
+            None => "might not be able to include",
+        };
+        Verdict {
+            compatible,
+            reasons: vec![format!("{} {} {}: {}", outer, relation, inner, reason)],
+        }
+    }
+
+    /// Three-valued conjunction, definitely incompatible wins over unknown
+    pub fn all<I: IntoIterator<Item=Verdict>>(verdicts: I) -> Verdict {
+        Verdict::combine(verdicts, false)
+    }
+
+    /// Three-valued disjunction, definitely compatible wins over unknown
+    pub fn any<I: IntoIterator<Item=Verdict>>(verdicts: I) -> Verdict {
+        Verdict::combine(verdicts, true)
+    }
+
+    fn combine<I: IntoIterator<Item=Verdict>>(verdicts: I, deciding: bool) -> Verdict {
+        let mut unknown = Vec::new();
+        let mut other = Vec::new();
+        for verdict in verdicts {
+            match verdict.compatible {
+                Some(compatible) if compatible == deciding => return verdict,
+                Some(_) => other.extend(verdict.reasons),
+                None => unknown.extend(verdict.reasons),
+            }
+        }
+        if unknown.is_empty() {
+            Verdict { compatible: Some(!deciding), reasons: other }
+        } else {
+            Verdict { compatible: None, reasons: unknown }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use toml;
+
+    use spdx::Category;
+
+    use super::{Matrix, Rules};
+
+    fn matrix(rules: &str) -> Matrix {
+        let rules: Rules = toml::from_str(rules).unwrap();
+        let mut matrix = Matrix { rules: Vec::new(), known: Default::default() };
+        matrix.extend(rules.rule);
+        matrix
+    }
+
+    fn compatible(matrix: &Matrix, outer: &str, inner: &str) -> Option<bool> {
+        matrix.check(outer, Some(Category::Permissive), inner, Some(Category::Permissive)).compatible
+    }
+
+    #[test]
+    fn later_rules_override_earlier_ones() {
+        let matrix = matrix(r#"
+            [[rule]]
+            outer = ["MIT"]
+            inner = ["ISC"]
+            compatible = true
+            reason = "first"
+
+            [[rule]]
+            outer = ["MIT"]
+            inner = ["ISC"]
+            compatible = false
+            reason = "second"
+        "#);
+        let verdict = matrix.check("MIT", None, "ISC", None);
+        assert_eq!(verdict.compatible, Some(false));
+        assert!(verdict.reasons[0].ends_with("second"));
+    }
+
+    #[test]
+    fn later_wildcards_override_earlier_rules() {
+        let matrix = matrix(r#"
+            [[rule]]
+            outer = ["MIT"]
+            inner = ["ISC", "Zlib"]
+            compatible = true
+            reason = "specific"
+
+            [[rule]]
+            outer = ["*"]
+            inner = ["ISC"]
+            reason = "any"
+        "#);
+        assert_eq!(compatible(&matrix, "MIT", "ISC"), None);
+        assert_eq!(compatible(&matrix, "MIT", "Zlib"), Some(true));
+    }
+
+    #[test]
+    fn later_rules_override_earlier_wildcards() {
+        let matrix = matrix(r#"
+            [[rule]]
+            outer = ["*"]
+            inner = ["*"]
+            compatible = false
+            reason = "any"
+
+            [[rule]]
+            outer = ["MIT"]
+            inner = ["ISC"]
+            compatible = true
+            reason = "specific"
+        "#);
+        assert_eq!(compatible(&matrix, "MIT", "ISC"), Some(true));
+        assert_eq!(compatible(&matrix, "ISC", "MIT"), Some(false));
+        assert_eq!(compatible(&matrix, "Zlib", "Zlib"), Some(false));
+    }
+
+    #[test]
+    fn extending_overrides_existing_rules() {
+        let mut matrix = matrix(r#"
+            [[rule]]
+            outer = ["MIT"]
+            inner = ["ISC"]
+            compatible = true
+            reason = "bundled"
+        "#);
+        let rules: Rules = toml::from_str(r#"
+            [[rule]]
+            outer = ["*"]
+            inner = ["ISC"]
+            compatible = false
+            reason = "project"
+        "#).unwrap();
+        matrix.extend(rules.rule);
+        assert_eq!(compatible(&matrix, "MIT", "ISC"), Some(false));
+    }
+
+    #[test]
+    fn identifiers_are_normalized() {
+        let matrix = matrix(r#"
+            [[rule]]
+            outer = ["GPL-2.0+", "gpl-3.0"]
+            inner = ["mit"]
+            compatible = true
+            reason = "normalized"
+        "#);
+        assert_eq!(compatible(&matrix, "GPL-2.0-or-later", "MIT"), Some(true));
+        assert_eq!(compatible(&matrix, "GPL-3.0-only", "MIT"), Some(true));
+    }
+
+    #[test]
+    fn bundled_lgpl_2_0() {
+        let matrix = Matrix::bundled().unwrap();
+        assert_eq!(compatible(&matrix, "GPL-2.0-only", "LGPL-2.0-only"), Some(true));
+        assert_eq!(compatible(&matrix, "GPL-3.0-or-later", "LGPL-2.0-or-later"), Some(true));
+        assert_eq!(compatible(&matrix, "LGPL-2.0-only", "MIT"), Some(true));
+        assert_eq!(compatible(&matrix, "LGPL-2.0-or-later", "Apache-2.0"), Some(false));
+        assert_eq!(compatible(&matrix, "MIT", "LGPL-2.0-only"), Some(false));
+        assert_eq!(compatible(&matrix, "LGPL-2.0-only", "GPL-2.0-only"), Some(false));
+    }
+
+    #[test]
+    fn bundled_common_crates_io_licenses() {
+        let matrix = Matrix::bundled().unwrap();
+        for inner in &["ISC", "Zlib", "BSL-1.0", "Unicode-DFS-2016", "Unicode-3.0", "MIT-0"] {
+            for outer in &["MIT", "ISC", "Apache-2.0", "GPL-2.0-only", "NONE"] {
+                assert_eq!(compatible(&matrix, outer, inner), Some(true), "{} including {}", outer, inner);
+            }
+        }
+        assert_eq!(compatible(&matrix, "Zlib", "GPL-3.0-only"), Some(false));
+        assert_eq!(compatible(&matrix, "ISC", "Apache-2.0"), Some(false));
+    }
+
+    #[test]
+    fn bundled_licenses_all_have_verdicts() {
+        let matrix = Matrix::bundled().unwrap();
+        for outer in &matrix.known {
+            for inner in &matrix.known {
+                let verdict = matrix.check(outer, None, inner, None);
+                assert!(verdict.compatible.is_some(), "{:?}", verdict.reasons);
+            }
+        }
+    }
+
+    #[test]
+    fn known_licenses_without_a_rule_are_incompatible() {
+        let matrix = matrix(r#"
+            [[rule]]
+            outer = ["MIT"]
+            inner = ["ISC"]
+            compatible = true
+            reason = "known"
+        "#);
+        assert_eq!(compatible(&matrix, "ISC", "MIT"), Some(false));
+        // Falls back to the categories for licenses the matrix doesn't know
+        assert_eq!(compatible(&matrix, "MIT", "Zlib"), Some(true));
+    }
+}
@@ -1,163 +1,732 @@
+use std::ffi::OsStr;
 use std::io::Read as R;
-use std::fs::{self, File};
+use std::cell::RefCell;
+use std::fs::File;
 use std::path::{Path, PathBuf};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
 
+use glob::{MatchOptions, Pattern};
 use regex::Regex;
 use cargo::CargoResult;
 use cargo::core::Package;
+use void::ResultVoidExt;
+use walkdir::WalkDir;
 
 use license::License;
+use spdx;
+use template::{Match, Parser, Template, Text};
 
-const HIGH_CONFIDENCE_LIMIT: f32 = 0.10;
-const LOW_CONFIDENCE_LIMIT: f32 = 0.15;
+/// Scores from comparing a text to a license template that we're confident,
+/// or only semi-confident, mean the text is for that license
+const CONFIDENT_SCORE: f32 = 0.90;
+const SEMI_CONFIDENT_SCORE: f32 = 0.85;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Confidence {
     Confident,
     SemiConfident,
     Unsure,
 }
 
+/// A candidate text for a license, `path` is relative to the package root
 pub struct LicenseText {
     pub path: PathBuf,
     pub text: String,
+    /// How similar the text is to the license's template, from 0 to 1, if we
+    /// have a template for it
+    pub score: Option<f32>,
     pub confidence: Confidence,
+    /// The text in place of the variable parts of the template, like the
+    /// copyright holder
+    pub variables: BTreeMap<String, String>,
 }
 
-fn read(path: &Path) -> CargoResult<String> {
+/// A license file for something other than the package itself
+pub struct LicenseFile {
+    pub path: PathBuf,
+    pub text: String,
+}
+
+/// A license given by an `SPDX-License-Identifier` comment in a source file
+pub struct SpdxHeader {
+    pub path: PathBuf,
+    pub license: License,
+}
+
+/// A copyright notice for a package, `path` is the file it was found in, or
+/// `None` if it was made up from the package's authors
+pub struct Copyright {
+    pub notice: String,
+    pub path: Option<PathBuf>,
+}
+
+pub fn read(path: &Path) -> CargoResult<String> {
     let mut s = String::new();
     File::open(path)?.read_to_string(&mut s)?;
     Ok(s)
 }
 
-fn add_frequencies(freq: &mut HashMap<String, u32>, text: &str) {
-    for word in Regex::new(r"\w+").unwrap().find_iter(text) {
-        *freq.entry(word.as_str().to_lowercase().to_owned()).or_insert(0) += 1;
+impl Confidence {
+    pub fn from_score(score: Option<f32>) -> Confidence {
+        match score {
+            Some(score) if score >= CONFIDENT_SCORE => Confidence::Confident,
+            Some(score) if score >= SEMI_CONFIDENT_SCORE => Confidence::SemiConfident,
+            _ => Confidence::Unsure,
+        }
+    }
+
+    /// How confident we are in a match, a text with the whole license and
+    /// something more might have changed its terms
+    fn from_match(found: Option<&Match>) -> Confidence {
+        match Confidence::from_score(found.map(|found| found.score)) {
+            Confidence::Confident if found.map_or(false, |found| found.extended) => Confidence::SemiConfident,
+            confidence => confidence,
+        }
     }
 }
 
-fn calculate_frequency(text: &str) -> HashMap<String, u32> {
-    let mut freq = HashMap::new();
-    add_frequencies(&mut freq, text);
-    freq
+/// The known license a text is most similar to
+#[derive(Clone)]
+pub struct Classification {
+    pub license: License,
+    /// How similar the text is to the license's template, from 0 to 1
+    pub score: f32,
 }
 
-fn compare(mut text_freq: HashMap<String, u32>, template_freq: &HashMap<String, u32>) -> u32 {
-    let mut errors = 0;
+/// Patterns for the names of files that may contain license texts, matched
+/// case-insensitively
+const DEFAULT_PATTERNS: &[&str] = &[
+    "LICEN[CS]E*",
+    "COPYING*",
+    "COPYRIGHT*",
+    "UNLICENSE*",
+    "NOTICE*",
+];
 
-    for (word, &count) in template_freq {
-        let text_count = text_freq.remove(word).unwrap_or(0);
-        let diff = ((text_count as i32) - (count as i32)).abs() as u32;
-        errors += diff;
-    }
+/// Directories that only contain license texts, like the `LICENSES/` of the
+/// REUSE specification
+const LICENSE_DIRS: &[&str] = &["licenses", "license"];
 
-    for (_, count) in text_freq {
-        errors += count;
-    }
+/// Files with these extensions are code, even when named like a license text
+/// as in `license.rs`
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "c", "cc", "cpp", "go", "h", "hpp", "java", "js", "json", "py", "rs", "sh", "toml", "ts", "yaml", "yml",
+];
+
+/// Directories in the package root holding its own code, anything named like
+/// a license text in them is data used by the code, e.g. in a license checker
+const SOURCE_DIRS: &[&str] = &["src", "tests", "benches", "examples"];
+
+const DEFAULT_DEPTH: usize = 3;
+
+/// How far into a source file to look for an SPDX header
+const HEADER_LINES: usize = 20;
 
-    errors
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: false,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// The `[discovery]` section of a `lichking.toml`
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Options {
+    depth: Option<usize>,
+    patterns: Vec<String>,
 }
 
-fn check_against_template(text: &str, license: &License) -> Confidence {
-    let text_freq = calculate_frequency(text);
+/// A package's license files that look like a license we know
+type Classified = Vec<(PathBuf, Classification)>;
+
+/// Finds the license files in packages
+pub struct Discovery {
+    depth: usize,
+    /// The default patterns, matching file names
+    names: Vec<Pattern>,
+    /// Patterns from the options, matching paths relative to the package root
+    patterns: Vec<Pattern>,
+    parser: Parser,
+    year: Regex,
+    /// A placeholder for the year or owner in a template's copyright notice
+    placeholder: Regex,
+    /// A mention of a `NOTICE` file
+    notice: Regex,
+    spdx_header: Regex,
+    /// Templates parsed so far, by their source
+    templates: RefCell<HashMap<String, Rc<Template>>>,
+    /// The license files found in each package, by its root
+    license_files: RefCell<HashMap<PathBuf, Rc<Vec<PathBuf>>>>,
+    /// What each of a package's license files looks like, by its root
+    classified: RefCell<HashMap<PathBuf, Rc<Classified>>>,
+}
+
+impl Discovery {
+    pub fn new(options: Options) -> CargoResult<Discovery> {
+        let patterns = options.patterns.iter()
+            .map(|pattern| Pattern::new(pattern).map_err(|err| format_err!("invalid discovery pattern '{}': {}", pattern, err)))
+            .collect::<CargoResult<_>>()?;
+
+        Ok(Discovery {
+            depth: options.depth.unwrap_or(DEFAULT_DEPTH),
+            names: default_names(),
+            patterns,
+            parser: Parser::new(),
+            year: Regex::new(r"\b(19|20)\d\d\b").unwrap(),
+            placeholder: Regex::new(r"(?i)[\[<{][^\]>}@:/]*\b(yyyy|years?|name|owner|holders?|authors?|fullname)\b[^\]>}@:/]*[\]>}]").unwrap(),
+            notice: Regex::new(r"\bNOTICE\b").unwrap(),
+            spdx_header: Regex::new(r"SPDX-License-Identifier:\s*(.+?)\s*(\*/)?\s*$").unwrap(),
+            templates: RefCell::new(HashMap::new()),
+            license_files: RefCell::new(HashMap::new()),
+            classified: RefCell::new(HashMap::new()),
+        })
+    }
+
+    /// Parses a license template, only once however often it's used
+    pub fn template(&self, source: &str) -> Rc<Template> {
+        if let Some(template) = self.templates.borrow().get(source) {
+            return template.clone();
+        }
+        let template = Rc::new(self.parser.template(source));
+        self.templates.borrow_mut().insert(source.to_owned(), template.clone());
+        template
+    }
 
-    let template_freq = if let License::Multiple(ref licenses) = *license {
-        let mut template_freq = HashMap::new();
-        for license in licenses {
-            if let Some(template) = license.template() {
-                add_frequencies(&mut template_freq, template)
-            } else {
-                return Confidence::Unsure
+    /// Matches a text against the templates for every part of `license`,
+    /// `None` if we don't have a template for some part of it
+    fn match_against_template(&self, text: &Text, license: &License) -> Option<Match> {
+        let mut template = String::new();
+        for license in license.simple_licenses() {
+            template.push_str(license.template()?);
+            if let License::With(_, ref exception) = *license {
+                if let Some(exception) = exception.template() {
+                    template.push_str(exception);
+                }
             }
         }
-        template_freq
-    } else if let Some(template) = license.template() {
-        calculate_frequency(template)
-    } else {
-        return Confidence::Unsure
-    };
+        Some(self.template(&template).matches(text))
+    }
 
-    let total: u32 = template_freq.values().sum();
-    let errors = compare(text_freq, &template_freq);
-    let score = (errors as f32) / (total as f32);
+    /// Compares a text against the template of every license we know,
+    /// returning the closest if it's close enough to be that license. The
+    /// candidates are the licenses on the SPDX list that aren't deprecated and
+    /// have a template, skipping those too different in length to be closer
+    pub fn classify(&self, text: &Text) -> Option<Classification> {
+        let candidates = spdx::LICENSES.iter()
+            .filter(|license| !license.deprecated)
+            .map(License::Spdx)
+            .filter_map(|license| license.template().map(|template| (license, template)));
 
-    if score < HIGH_CONFIDENCE_LIMIT {
-        Confidence::Confident
-    } else if score < LOW_CONFIDENCE_LIMIT {
-        Confidence::SemiConfident
-    } else {
-        Confidence::Unsure
+        let mut best: Option<Classification> = None;
+        for (license, template) in candidates {
+            let template = self.template(template);
+            let threshold = best.as_ref().map_or(SEMI_CONFIDENT_SCORE, |best| best.score);
+            if template.max_score(text) < threshold {
+                continue;
+            }
+            let score = template.score(text);
+            // Variants like `-only` and `-or-later` share a template, the
+            // first of them is reported
+            if score >= SEMI_CONFIDENT_SCORE && best.as_ref().map_or(true, |best| score > best.score) {
+                best = Some(Classification { license, score });
+            }
+        }
+        best
     }
-}
 
-pub fn find_generic_license_text(package: &Package, license: &License) -> CargoResult<Option<LicenseText>> {
-    fn generic_license_name(name: &str) -> bool {
-        name.to_uppercase() == "LICENSE"
-            || name.to_uppercase() == "LICENCE"
-            || name.to_uppercase() == "LICENSE.MD"
-            || name.to_uppercase() == "LICENSE.TXT"
+    /// Every license file in the package, found by default or matching one of
+    /// the patterns, relative to the package root, files in the root come first
+    fn license_files(&self, package: &Package) -> CargoResult<Rc<Vec<PathBuf>>> {
+        if let Some(files) = self.license_files.borrow().get(package.root()) {
+            return Ok(files.clone());
+        }
+        let mut files = walk(package.root(), self.depth)?;
+        files.retain(|path| {
+            is_default_license_file(path, &self.names) || self.patterns.iter().any(|pattern| pattern.matches_path_with(path, &MATCH_OPTIONS))
+        });
+        files.sort_by_key(|path| path.components().count());
+        let files = Rc::new(files);
+        self.license_files.borrow_mut().insert(package.root().to_owned(), files.clone());
+        Ok(files)
     }
 
-    for entry in fs::read_dir(package.root())? {
-        let entry = entry?;
-        let path = entry.path().to_owned();
-        let name = entry.file_name().to_string_lossy().into_owned();
+    /// License files that apply to the package as a whole, in its root or a
+    /// directory of licenses like `LICENSES/` directly below it
+    fn package_license_files(&self, package: &Package) -> CargoResult<Vec<PathBuf>> {
+        Ok(self.license_files(package)?.iter().filter(|path| !is_vendored(path)).cloned().collect())
+    }
+
+    pub fn find_generic_license_text(&self, package: &Package, license: &License) -> CargoResult<Option<LicenseText>> {
+        let files = self.package_license_files(package)?;
+        let specific = files.iter().any(|path| path.file_name().map_or(false, |name| {
+            license.simple_licenses().into_iter().any(|license| license_name_matches(&name.to_string_lossy(), license))
+        }));
 
-        if generic_license_name(&name) {
-            if let Ok(text) = read(&path) {
-                let confidence = check_against_template(&text, license);
+        for path in files {
+            let name = match path.file_name() {
+                Some(name) => name.to_string_lossy().to_uppercase(),
+                None => continue,
+            };
+            let stem = name.trim_end_matches(".MD").trim_end_matches(".TXT");
+            // `COPYING` and `COPYRIGHT` files often only summarise the
+            // licenses and point to a file for each of them
+            let summary = stem == "COPYING" || stem == "COPYRIGHT";
+            if !(stem == "LICENSE" || stem == "LICENCE" || summary) {
+                continue;
+            }
+            if let Ok(text) = read(&package.root().join(&path)) {
+                let found = self.match_against_template(&self.parser.text(&text), license);
+                let score = found.as_ref().map(|found| found.score);
+                let confidence = Confidence::from_match(found.as_ref());
+                if summary && specific && confidence == Confidence::Unsure {
+                    continue;
+                }
                 return Ok(Some(LicenseText {
                     path,
                     text,
+                    score,
                     confidence,
+                    variables: found.map(|found| found.variables).unwrap_or_default(),
                 }));
             }
         }
+
+        Ok(None)
     }
 
-    Ok(None)
-}
+    pub fn find_license_text(&self, package: &Package, license: &License) -> CargoResult<Vec<LicenseText>> {
+        let mut texts = Vec::new();
+        for path in self.package_license_files(package)? {
+            if path.file_name().map_or(false, |name| license_name_matches(&name.to_string_lossy(), license)) {
+                if let Ok(text) = read(&package.root().join(&path)) {
+                    let found = self.match_against_template(&self.parser.text(&text), license);
+                    let score = found.as_ref().map(|found| found.score);
+                    texts.push(LicenseText {
+                        path,
+                        text,
+                        score,
+                        confidence: Confidence::from_match(found.as_ref()),
+                        variables: found.map(|found| found.variables).unwrap_or_default(),
+                    });
+                }
+            }
+        }
 
-pub fn find_license_text(package: &Package, license: &License) -> CargoResult<Vec<LicenseText>> {
-    fn read(path: &Path) -> CargoResult<String> {
-        let mut s = String::new();
-        File::open(path)?.read_to_string(&mut s)?;
-        Ok(s)
+        Ok(texts)
     }
 
-    fn name_matches(name: &str, license: &License) -> bool {
-        let name = name.to_uppercase();
-        match *license {
-            License::Apache_2_0 => name == "LICENSE-APACHE",
-            License::Custom(ref custom) => {
-                let custom = custom.to_uppercase();
-                name == custom || name == format!("LICENSE-{}", custom)
+    /// The `NOTICE` file in the root of the package, which Apache-2.0 requires
+    /// redistributing along with the license
+    pub fn find_notice(&self, package: &Package) -> CargoResult<Option<LicenseFile>> {
+        for path in self.package_license_files(package)? {
+            if path.components().count() == 1 && is_notice(&path) {
+                if let Ok(text) = read(&package.root().join(&path)) {
+                    return Ok(Some(LicenseFile { path, text }));
+                }
             }
-            ref license => {
-                let license = license.to_string().to_uppercase();
-                name == license || name == format!("LICENSE-{}", license)
+        }
+        Ok(None)
+    }
+
+    /// Whether the package's license files or readme mention a `NOTICE` file,
+    /// beyond the mentions of one in the standard Apache-2.0 text
+    pub fn references_notice(&self, package: &Package) -> CargoResult<bool> {
+        let pattern = &self.notice;
+        let apache = "Apache-2.0".parse::<License>().void_unwrap();
+        let standard = apache.template().map_or(0, |template| pattern.find_iter(template).count());
+
+        for path in self.package_license_files(package)? {
+            if is_notice(&path) {
+                continue;
+            }
+            if let Ok(text) = read(&package.root().join(&path)) {
+                if pattern.find_iter(&text).count() > standard {
+                    return Ok(true);
+                }
+            }
+        }
+
+        let readme = package.manifest().metadata().readme.clone().unwrap_or_else(|| "README.md".to_owned());
+        if let Ok(text) = read(&package.root().join(readme)) {
+            if pattern.is_match(&text) {
+                return Ok(true);
             }
         }
+
+        Ok(false)
     }
 
-    let mut texts = Vec::new();
-    for entry in fs::read_dir(package.root())? {
-        let entry = entry?;
-        let path = entry.path().to_owned();
-        let name = entry.file_name().to_string_lossy().into_owned();
+    /// What each of the package's license files looks like, if it's close
+    /// enough to a license we know, only worked out once for each package
+    fn classified(&self, package: &Package) -> CargoResult<Rc<Classified>> {
+        if let Some(classified) = self.classified.borrow().get(package.root()) {
+            return Ok(classified.clone());
+        }
+        let mut classified = Vec::new();
+        for path in self.package_license_files(package)? {
+            if is_notice(&path) {
+                continue;
+            }
+            if let Ok(text) = read(&package.root().join(&path)) {
+                if let Some(classification) = self.classify(&self.parser.text(&text)) {
+                    classified.push((path, classification));
+                }
+            }
+        }
+        let classified = Rc::new(classified);
+        self.classified.borrow_mut().insert(package.root().to_owned(), classified.clone());
+        Ok(classified)
+    }
 
-        if name_matches(&name, license) {
-            if let Ok(text) = read(&path) {
-                let confidence = check_against_template(&text, license);
-                texts.push(LicenseText {
-                    path,
-                    text,
-                    confidence,
+    /// License files in the package that look like a known license other than
+    /// the ones in `license`
+    pub fn find_mismatched_license_texts(&self, package: &Package, license: &License) -> CargoResult<Vec<(PathBuf, Classification)>> {
+        if let License::Unspecified | License::File(_) = *license {
+            return Ok(Vec::new());
+        }
+        let expected = license.simple_licenses();
+        Ok(self.classified(package)?
+            .iter()
+            .filter(|&&(_, ref classification)| {
+                let template = classification.license.template();
+                !expected.iter().any(|license| license.template() == template)
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// The copyright notices in the package's license files and the headers
+    /// of its source files, if there are none one is made from its authors
+    pub fn find_copyrights(&self, package: &Package, license: &License) -> CargoResult<Vec<Copyright>> {
+        // Some license texts have their own copyright notice, like the FSF's
+        // in the GPL, which isn't one for the package
+        let templates = license.simple_licenses()
+            .into_iter()
+            .filter_map(License::template)
+            .map(|template| normalize_notice(&self.template(template).text()))
+            .collect::<Vec<_>>();
+
+        let mut copyrights: Vec<Copyright> = Vec::new();
+        {
+            let mut add = |path: &Path, text: &str, lines: usize| {
+                for notice in text.lines().take(lines).filter_map(|line| self.copyright_notice(line)) {
+                    let normalized = normalize_notice(notice);
+                    if templates.iter().any(|template| template.contains(&normalized)) {
+                        continue;
+                    }
+                    if !copyrights.iter().any(|copyright| normalize_notice(&copyright.notice) == normalized) {
+                        copyrights.push(Copyright {
+                            notice: notice.to_owned(),
+                            path: Some(path.to_owned()),
+                        });
+                    }
+                }
+            };
+
+            for path in self.package_license_files(package)? {
+                if let Ok(text) = read(&package.root().join(&path)) {
+                    add(&path, &text, usize::max_value());
+                }
+            }
+            for path in self.source_files(package)? {
+                if let Ok(text) = read(&package.root().join(&path)) {
+                    add(&path, &text, HEADER_LINES);
+                }
+            }
+        }
+
+        if copyrights.is_empty() {
+            let authors = package.manifest().metadata().authors
+                .iter()
+                .map(|author| match author.find('<') {
+                    Some(email) => author[..email].trim(),
+                    None => author.trim(),
+                })
+                .filter(|author| !author.is_empty())
+                .collect::<Vec<_>>();
+            if !authors.is_empty() {
+                copyrights.push(Copyright {
+                    notice: format!("Copyright (c) {}", authors.join(", ")),
+                    path: None,
                 });
             }
         }
+
+        Ok(copyrights)
+    }
+
+    /// License files belonging to sub-components vendored into the package,
+    /// e.g. C libraries under `vendor/` or `third_party/`
+    pub fn find_vendored_license_texts(&self, package: &Package) -> CargoResult<Vec<LicenseFile>> {
+        let mut texts = Vec::new();
+        for path in self.license_files(package)?.iter() {
+            if is_vendored(path) {
+                if let Ok(text) = read(&package.root().join(path)) {
+                    texts.push(LicenseFile { path: path.clone(), text });
+                }
+            }
+        }
+        Ok(texts)
+    }
+
+    /// The licenses given by `SPDX-License-Identifier` comments at the top of
+    /// the package's Rust source files
+    pub fn find_spdx_headers(&self, package: &Package) -> CargoResult<Vec<SpdxHeader>> {
+        let mut headers = Vec::new();
+        for path in self.source_files(package)? {
+            if let Ok(text) = read(&package.root().join(&path)) {
+                let found = text.lines()
+                    .take(HEADER_LINES)
+                    .filter_map(|line| self.spdx_header.captures(line))
+                    .next();
+                if let Some(captures) = found {
+                    let license = captures[1].parse::<License>().void_unwrap();
+                    headers.push(SpdxHeader { path, license });
+                }
+            }
+        }
+        Ok(headers)
     }
 
-    Ok(texts)
+    /// The package's build script and the Rust source files in its `src/`
+    /// directory, up to the search depth, relative to the package root
+    fn source_files(&self, package: &Package) -> CargoResult<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        if package.root().join("build.rs").is_file() {
+            files.push(PathBuf::from("build.rs"));
+        }
+        let src = package.root().join("src");
+        if src.is_dir() {
+            files.extend(walk(&src, self.depth)?
+                .into_iter()
+                .filter(|path| path.extension().map_or(false, |extension| extension == "rs"))
+                .map(|path| Path::new("src").join(path)));
+        }
+        Ok(files)
+    }
+
+    /// The copyright notice on a line, if there is one. It has to give a year
+    /// or a copyright sign to tell it apart from lines of a license text that
+    /// start with "copyright", and placeholders like `Copyright [yyyy] [name
+    /// of copyright owner]` in templates aren't notices
+    fn copyright_notice<'a>(&self, line: &'a str) -> Option<&'a str> {
+        let line = line.trim().trim_start_matches(|c| c == '/' || c == '#' || c == '*' || c == '!').trim_end_matches("*/").trim();
+        let lower = line.to_lowercase();
+        let copyright = lower.starts_with("copyright") || lower.starts_with('©') || lower.starts_with("(c)");
+        let dated_or_signed = self.year.is_match(line) || lower.contains("(c)") || lower.contains('©');
+        if copyright && dated_or_signed && !self.placeholder.is_match(line) {
+            Some(line)
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether a file is named after `license`, like `LICENSE-MIT`
+fn license_name_matches(name: &str, license: &License) -> bool {
+    let name = name.to_uppercase();
+    let name = name.trim_end_matches(".MD").trim_end_matches(".TXT");
+    let matches = |id: &str| {
+        name == id || name == format!("LICENSE-{}", id) || name == format!("COPYING-{}", id)
+    };
+    match *license {
+        License::Spdx(license) if license.id == "Apache-2.0" => name == "LICENSE-APACHE" || matches("APACHE-2.0"),
+        // Deprecated GNU identifiers are normalized when parsing, the
+        // file may still be named after the one the crate wrote
+        License::Spdx(license) if license.id.ends_with("-only") || license.id.ends_with("-or-later") => {
+            let id = license.id.to_uppercase();
+            let short = id.trim_end_matches("-ONLY").trim_end_matches("-OR-LATER");
+            matches(&id) || matches(short) || matches(&format!("{}+", short))
+        }
+        License::With(ref license, _) => license_name_matches(name, license),
+        License::Custom(ref custom) => matches(&custom.to_uppercase()),
+        ref license => matches(&license.to_string().to_uppercase()),
+    }
+}
+
+fn normalize_notice(notice: &str) -> String {
+    notice.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+fn is_notice(path: &Path) -> bool {
+    path.file_name().map_or(false, |name| {
+        let name = name.to_string_lossy().to_uppercase();
+        name == "NOTICE" || name == "NOTICE.TXT" || name == "NOTICE.MD"
+    })
+}
+
+fn default_names() -> Vec<Pattern> {
+    DEFAULT_PATTERNS.iter().map(|pattern| Pattern::new(pattern).unwrap()).collect()
+}
+
+/// Whether a file is a license text found by default, named like one or in a
+/// directory of them and not part of the package's code
+fn is_default_license_file(path: &Path, names: &[Pattern]) -> bool {
+    let lowercase = |name: &OsStr| name.to_string_lossy().to_lowercase();
+    let in_source_dir = path.parent().and_then(|parent| parent.components().next()).map_or(false, |dir| {
+        SOURCE_DIRS.contains(&&*lowercase(dir.as_os_str()))
+    });
+    let is_source = path.extension().map_or(false, |extension| SOURCE_EXTENSIONS.contains(&&*lowercase(extension)));
+    if in_source_dir || is_source {
+        return false;
+    }
+
+    let named = path.file_name().map_or(false, |name| {
+        names.iter().any(|pattern| pattern.matches_with(&name.to_string_lossy(), &MATCH_OPTIONS))
+    });
+    let in_license_dir = path.parent().and_then(Path::file_name).map_or(false, |dir| LICENSE_DIRS.contains(&&*lowercase(dir)));
+    named || in_license_dir
+}
+
+/// Every file in a package up to `depth` directories deep, relative to the
+/// package root
+fn walk(root: &Path, depth: usize) -> CargoResult<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let walker = WalkDir::new(root)
+        .max_depth(depth)
+        .sort_by(|a, b| a.file_name().cmp(b.file_name()))
+        .into_iter()
+        // Skip build output and version control directories
+        .filter_entry(|entry| entry.depth() == 0 || !(entry.file_type().is_dir() && (entry.file_name() == "target" || entry.file_name().to_string_lossy().starts_with('.'))));
+    for entry in walker {
+        let entry = entry?;
+        if entry.file_type().is_file() {
+            files.push(entry.path().strip_prefix(root)?.to_owned());
+        }
+    }
+    Ok(files)
+}
+
+/// Whether a license file is somewhere other than the package root or one of
+/// the license directories directly inside it
+fn is_vendored(path: &Path) -> bool {
+    match path.parent().map(|parent| parent.components().count()) {
+        None | Some(0) => false,
+        Some(1) => {
+            let dir = path.parent().unwrap().to_string_lossy().to_lowercase();
+            !LICENSE_DIRS.contains(&&*dir)
+        }
+        Some(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use void::ResultVoidExt;
+
+    use license::License;
+
+    use super::{default_names, is_default_license_file, is_vendored, Discovery, Options, CONFIDENT_SCORE};
+
+    fn classify(discovery: &Discovery, text: &str) -> Option<(String, f32)> {
+        discovery.classify(&discovery.parser.text(text))
+            .map(|classification| (classification.license.to_string(), classification.score))
+    }
+
+    fn text(discovery: &Discovery, license: &str) -> String {
+        let license = license.parse::<License>().void_unwrap();
+        discovery.template(license.template().unwrap()).text()
+    }
+
+    #[test]
+    fn classify_known_licenses() {
+        let discovery = Discovery::new(Options::default()).unwrap();
+        for license in &["MIT", "Apache-2.0", "BSD-3-Clause", "ISC", "MPL-2.0"] {
+            let (found, score) = classify(&discovery, &text(&discovery, license)).unwrap();
+            assert_eq!(found, *license);
+            assert!(score >= CONFIDENT_SCORE, "{} scored {}", license, score);
+        }
+        // Variants sharing a template are reported as the first of them
+        assert_eq!(classify(&discovery, &text(&discovery, "GPL-3.0-or-later")).map(|found| found.0), Some("GPL-3.0-only".to_owned()));
+    }
+
+    #[test]
+    fn classify_filled_in_licenses() {
+        let discovery = Discovery::new(Options::default()).unwrap();
+        let text = text(&discovery, "MIT").replace("Copyright <YEAR> <COPYRIGHT HOLDER>", "Copyright (c) 2016 Jane Doe");
+        assert_eq!(classify(&discovery, &text).map(|found| found.0), Some("MIT".to_owned()));
+        let text = text.replace("The above copyright notice", "This notice");
+        let (license, score) = classify(&discovery, &text).unwrap();
+        assert_eq!(license, "MIT");
+        assert!(score < 1.0);
+    }
+
+    #[test]
+    fn classify_other_texts() {
+        let discovery = Discovery::new(Options::default()).unwrap();
+        assert_eq!(classify(&discovery, ""), None);
+        assert_eq!(classify(&discovery, "This crate is licensed under the MIT license, see LICENSE-MIT for details."), None);
+        // Half of a license is too different from the whole license
+        let text = text(&discovery, "Apache-2.0");
+        assert_eq!(classify(&discovery, &text[..text.len() / 2]), None);
+    }
+
+    #[test]
+    fn default_license_files() {
+        let names = default_names();
+        let found = [
+            "LICENSE", "LICENSE-MIT", "license.md", "Licence.txt", "COPYING", "COPYRIGHT", "UNLICENSE", "NOTICE",
+            "LICENSES/MIT.txt", "LICENSES/Apache-2.0", "license/BSD", "vendor/zlib/LICENSE", "third_party/libfoo/COPYING",
+        ];
+        let ignored = [
+            "README.md", "license.rs", "src/license.rs", "src/licenses/MIT", "src/license/mod.rs", "tests/LICENSE",
+            "benches/licenses/MIT", "examples/NOTICE", "scripts/license.py", "vendor/zlib/license.c",
+        ];
+        for path in &found {
+            assert!(is_default_license_file(Path::new(path), &names), "{} is a license file", path);
+        }
+        for path in &ignored {
+            assert!(!is_default_license_file(Path::new(path), &names), "{} is not a license file", path);
+        }
+    }
+
+    #[test]
+    fn copyright_notices() {
+        let discovery = Discovery::new(Options::default()).unwrap();
+        let notices = [
+            "Copyright (c) 2016 Jane Doe",
+            "Copyright 2014-2018 The Rust Project Developers",
+            "Copyright (c) Microsoft Corporation.",
+            "Copyright © The Foo Authors",
+            "© 2017 John Doe <john@example.com>",
+            "(c) 2018 Someone <https://example.com/owner>",
+        ];
+        for notice in &notices {
+            assert_eq!(discovery.copyright_notice(notice), Some(*notice));
+        }
+        assert_eq!(discovery.copyright_notice("// Copyright (c) 2016 Jane Doe"), Some("Copyright (c) 2016 Jane Doe"));
+        assert_eq!(discovery.copyright_notice(" * Copyright 2016 Jane Doe */"), Some("Copyright 2016 Jane Doe"));
+    }
+
+    #[test]
+    fn copyright_placeholders() {
+        let discovery = Discovery::new(Options::default()).unwrap();
+        let lines = [
+            "Copyright [yyyy] [name of copyright owner]",
+            "Copyright <YEAR> <COPYRIGHT HOLDER>",
+            "Copyright (C) <year>  <name of author>",
+            "Copyright (c) 2016 [fullname]",
+            "Copyright (c) {year} {owner}",
+            "COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY,",
+            "Copyright notices must be kept",
+            "The above copyright notice (c) 2016",
+        ];
+        for line in &lines {
+            assert_eq!(discovery.copyright_notice(line), None, "{}", line);
+        }
+    }
+
+    #[test]
+    fn vendored() {
+        for path in &["LICENSE", "COPYING", "LICENSES/MIT.txt", "licenses/MIT", "license/BSD"] {
+            assert!(!is_vendored(Path::new(path)), "{} is not vendored", path);
+        }
+        for path in &["vendor/zlib/LICENSE", "third_party/LICENSE", "vendor/libfoo/LICENSES/MIT.txt", "deps/libgit2/COPYING"] {
+            assert!(is_vendored(Path::new(path)), "{} is vendored", path);
+        }
+    }
 }
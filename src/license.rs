@@ -4,37 +4,32 @@ use std::str::FromStr;
 
 use void::Void;
 
-#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
-#[allow(non_camel_case_types)]
+use compatibility::{Matrix, Verdict};
+use spdx::{self, ExceptionId, LicenseId};
+
+#[derive(Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
 pub enum License {
     // Licenses specified in the [SPDX License List](https://spdx.org/licenses/)
-    Unlicense,
-    BSD_0_Clause,
-    CC0_1_0,
-    MIT,
-    X11,
-    BSD_2_Clause,
-    BSD_3_Clause,
-    Apache_2_0,
-    LGPL_2_0,
-    LGPL_2_1,
-    LGPL_2_1Plus,
-    LGPL_3_0,
-    LGPL_3_0Plus,
-    MPL_1_1,
-    MPL_2_0,
-    GPL_2_0,
-    GPL_2_0Plus,
-    GPL_3_0,
-    GPL_3_0Plus,
-    AGPL_3_0,
-    AGPL_3_0Plus,
+    Spdx(&'static LicenseId),
 
     // Special cases
     Custom(String),
     File(PathBuf),
-    Multiple(Vec<License>),
     Unspecified,
+
+    // Compound expressions, see the SPDX specification [Appendix IV](https://spdx.github.io/spdx-spec/v2.3/SPDX-license-expressions/)
+    With(Box<License>, Exception),
+    And(Vec<License>),
+    Or(Vec<License>),
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
+pub enum Exception {
+    // Exceptions specified in the [SPDX License Exceptions List](https://spdx.org/licenses/exceptions-index.html)
+    Spdx(&'static ExceptionId),
+
+    // Special cases
+    Custom(String),
 }
 
 impl Default for License {
@@ -43,181 +38,559 @@ impl Default for License {
     }
 }
 
-macro_rules! compatibility {
-  ($s:expr, $o:expr, { $($a:pat => [$($b:pat),+])+ }) => {
-    #[allow(single_match)]
-    match $s {
-      $(
-        $a => match $o {
-          $($b)|+ => return Some(true),
-          _ => (),
-        }
-      ),*
-    }
-  };
-}
-
 impl License {
-    pub fn can_include(&self, other: &License) -> Option<bool> {
+    pub fn can_include(&self, other: &License, matrix: &Matrix) -> Verdict {
         use self::License::*;
 
-        if let Unspecified = *other { return Some(false); }
+        if let Unspecified = *other {
+            return Verdict::new(&self.to_string(), "NONE", Some(false), "no license has been given to use the code");
+        }
 
-        if let Custom(_) = *self { return None; }
-        if let Custom(_) = *other { return None; }
-        if let File(_) = *self { return None; }
-        if let File(_) = *other { return None; }
+        // We may end up distributed under any of our own licenses, so every
+        // one of them must be able to include the other package
+        match *self {
+            And(ref licenses) | Or(ref licenses) => {
+                return Verdict::all(licenses.iter().map(|license| license.can_include(other, matrix)));
+            }
+            _ => (),
+        }
 
-        if let Multiple(ref licenses) = *self {
-            for license in licenses {
-                if let Some(can_include) = license.can_include(other) {
-                    if !can_include {
-                        return Some(false);
-                    }
-                } else {
-                    return None;
+        // We must comply with every license in a conjunction, but only need
+        // to pick one out of a disjunction
+        match *other {
+            And(ref licenses) => {
+                return Verdict::all(licenses.iter().map(|license| self.can_include(license, matrix)));
+            }
+            Or(ref licenses) => {
+                return Verdict::any(licenses.iter().map(|license| self.can_include(license, matrix)));
+            }
+            _ => (),
+        }
+
+        // Exceptions only grant additional permissions, so they don't change
+        // what we are able to include
+        if let With(ref license, _) = *self {
+            return license.can_include(other, matrix);
+        }
+
+        // Linking exceptions let us include an otherwise incompatible
+        // license, unknown exceptions might do the same
+        if let With(ref license, ref exception) = *other {
+            let verdict = self.can_include(license, matrix);
+            return match verdict.compatible {
+                Some(true) => verdict,
+                _ if exception.permits_linking() => {
+                    Verdict::new(&self.to_string(), &other.to_string(), Some(true), &format!("{} permits linking with code under any license", exception))
+                }
+                Some(false) if exception.is_custom() => {
+                    Verdict::new(&self.to_string(), &other.to_string(), None, &format!("{} might permit this", exception))
                 }
+                _ => verdict,
+            };
+        }
+
+        match (self, other) {
+            (&Spdx(outer), &Spdx(inner)) => {
+                matrix.check(outer.id, Some(outer.category), inner.id, Some(inner.category))
+            }
+            (&Unspecified, &Spdx(inner)) => {
+                matrix.check("NONE", None, inner.id, Some(inner.category))
+            }
+            _ => {
+                Verdict::new(&self.to_string(), &other.to_string(), None, "only SPDX licenses can be checked")
             }
-            return Some(true);
         }
+    }
 
-        if let Multiple(ref licenses) = *other {
-            let mut seen_none = false;
-            for license in licenses {
-                if let Some(can_include) = self.can_include(license) {
-                    if can_include {
-                        return Some(true);
-                    }
+    /// Whether this is an `AND` or `OR` expression made up of other licenses
+    pub fn is_compound(&self) -> bool {
+        match *self {
+            License::And(_) | License::Or(_) => true,
+            _ => false,
+        }
+    }
+
+    /// The individual licenses this expression is built from, in order
+    pub fn simple_licenses(&self) -> Vec<&License> {
+        match *self {
+            License::And(ref licenses) | License::Or(ref licenses) => {
+                licenses.iter().flat_map(License::simple_licenses).collect()
+            }
+            ref license => vec![license],
+        }
+    }
+
+    /// All of `licenses`, normalized the same way as when parsing
+    pub fn and(licenses: Vec<License>) -> License {
+        License::compound(licenses, License::And, |license| match license {
+            License::And(licenses) => Ok(licenses),
+            license => Err(license),
+        })
+    }
+
+    fn or(licenses: Vec<License>) -> License {
+        License::compound(licenses, License::Or, |license| match license {
+            License::Or(licenses) => Ok(licenses),
+            license => Err(license),
+        })
+    }
+
+    /// Flattens nested uses of the same operator and sorts the operands so
+    /// that equivalent expressions compare equal
+    fn compound<C, F>(licenses: Vec<License>, constructor: C, unwrap: F) -> License
+            where C: FnOnce(Vec<License>) -> License,
+                  F: Fn(License) -> Result<Vec<License>, License> {
+        let mut flattened = Vec::new();
+        for license in licenses {
+            match unwrap(license) {
+                Ok(licenses) => flattened.extend(licenses),
+                Err(license) => flattened.push(license),
+            }
+        }
+        flattened.sort();
+        flattened.dedup();
+        if flattened.len() == 1 {
+            flattened.pop().expect("checked length")
+        } else {
+            constructor(flattened)
+        }
+    }
+
+    fn simple(id: &str) -> License {
+        if let Some(&(_, license, exception)) = DEPRECATED_WITH.iter().find(|&&(deprecated, _, _)| deprecated.eq_ignore_ascii_case(id)) {
+            return License::With(Box::new(License::simple(license)), exception.parse().expect("infallible"));
+        }
+
+        // The deprecated GNU identifiers are equivalent to the `-only` and
+        // `-or-later` variants, normalize them so they compare equal
+        let license = if id.ends_with('+') {
+            find_license(&format!("{}-or-later", &id[..id.len() - 1]))
+        } else {
+            find_license(id).map(|license| {
+                if license.deprecated {
+                    find_license(&format!("{}-only", license.id)).unwrap_or(license)
                 } else {
-                    seen_none = true;
+                    license
                 }
-            }
-            return if seen_none { None } else { Some(false) };
-        }
-
-        if let LGPL_2_0 = *self { return None; /* TODO: unknown */ }
-        if let LGPL_2_0 = *other { return None; /* TODO: unknown */ }
-
-        compatibility!(*self, *other, {
-            Unspecified         => [Unlicense, MIT, X11, BSD_2_Clause, BSD_3_Clause]
-
-            LGPL_2_0     => [LGPL_2_0] // TODO: probably allows more
-
-            Unlicense    => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11]
-            BSD_0_Clause => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11]
-            CC0_1_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11]
-            MIT          => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11]
-            X11          => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11]
-            BSD_2_Clause => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause]
-            BSD_3_Clause => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause]
-            Apache_2_0   => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, Apache_2_0]
-            MPL_1_1      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_1_1]
-            MPL_2_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, Apache_2_0, MPL_2_0]
-            LGPL_2_1Plus => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus]
-            LGPL_2_1     => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus, LGPL_2_1]
-            LGPL_3_0Plus => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_3_0Plus]
-            LGPL_3_0     => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_3_0Plus, LGPL_3_0]
-            GPL_2_0Plus  => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus]
-            GPL_2_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_2_0]
-            GPL_3_0Plus  => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus]
-            GPL_3_0      => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus, GPL_3_0]
-            AGPL_3_0Plus => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus, GPL_3_0, AGPL_3_0Plus]
-            AGPL_3_0     => [Unlicense, BSD_0_Clause, CC0_1_0, MIT, X11, BSD_2_Clause, BSD_3_Clause, MPL_2_0, Apache_2_0, LGPL_2_1Plus, LGPL_2_1, GPL_2_0Plus, GPL_3_0Plus, GPL_3_0, AGPL_3_0Plus, AGPL_3_0]
-
-            // TODO: These are `unreachable!()`, can't figure out a nice way to allow this in the macro...
-            Custom(_)    => [MIT]
-            File(_)      => [MIT]
-            Multiple(_)  => [MIT]
-        });
-
-        Some(false)
+            })
+        };
+        license.map(License::Spdx).unwrap_or_else(|| License::Custom(id.to_owned()))
     }
 
+    /// The SPDX template for a simple license, compound licenses have one for
+    /// each of their `simple_licenses`
     pub fn template(&self) -> Option<&'static str> {
         Some(match *self {
-            License::Unlicense     => include_str!("licenses/Unlicense"),
-            License::MIT           => include_str!("licenses/MIT"),
-            License::Apache_2_0    => include_str!("licenses/Apache-2.0"),
-            License::BSD_3_Clause    => include_str!("licenses/BSD-3-Clause"),
-            License::Multiple(_)   => { panic!("TODO: Refactor multiple handling") }
-            _                      => { return None }
+            License::Spdx(license) => match license.id {
+                "0BSD"                                   => include_str!("licenses/0BSD"),
+                "AGPL-3.0-only" | "AGPL-3.0-or-later"    => include_str!("licenses/AGPL-3.0"),
+                "Apache-1.1"                             => include_str!("licenses/Apache-1.1"),
+                "Apache-2.0"                             => include_str!("licenses/Apache-2.0"),
+                "BSD-1-Clause"                           => include_str!("licenses/BSD-1-Clause"),
+                "BSD-2-Clause"                           => include_str!("licenses/BSD-2-Clause"),
+                "BSD-3-Clause"                           => include_str!("licenses/BSD-3-Clause"),
+                "BSL-1.0"                                => include_str!("licenses/BSL-1.0"),
+                "CC0-1.0"                                => include_str!("licenses/CC0-1.0"),
+                "EPL-2.0"                                => include_str!("licenses/EPL-2.0"),
+                "GPL-2.0-only" | "GPL-2.0-or-later"      => include_str!("licenses/GPL-2.0"),
+                "GPL-3.0-only" | "GPL-3.0-or-later"      => include_str!("licenses/GPL-3.0"),
+                "ISC"                                    => include_str!("licenses/ISC"),
+                "LGPL-2.0-only" | "LGPL-2.0-or-later"    => include_str!("licenses/LGPL-2.0"),
+                "LGPL-2.1-only" | "LGPL-2.1-or-later"    => include_str!("licenses/LGPL-2.1"),
+                "LGPL-3.0-only" | "LGPL-3.0-or-later"    => include_str!("licenses/LGPL-3.0"),
+                "MIT"                                    => include_str!("licenses/MIT"),
+                "MIT-0"                                  => include_str!("licenses/MIT-0"),
+                "MPL-1.1"                                => include_str!("licenses/MPL-1.1"),
+                "MPL-2.0"                                => include_str!("licenses/MPL-2.0"),
+                "NCSA"                                   => include_str!("licenses/NCSA"),
+                "OpenSSL"                                => include_str!("licenses/OpenSSL"),
+                "Unicode-3.0"                            => include_str!("licenses/Unicode-3.0"),
+                "Unicode-DFS-2016"                       => include_str!("licenses/Unicode-DFS-2016"),
+                "Unlicense"                              => include_str!("licenses/Unlicense"),
+                "WTFPL"                                  => include_str!("licenses/WTFPL"),
+                "X11"                                    => include_str!("licenses/X11"),
+                "Zlib"                                   => include_str!("licenses/Zlib"),
+                _                                        => { return None }
+            },
+            License::With(ref license, _) => { return license.template() }
+            _                                            => { return None }
         })
     }
 }
 
+/// Deprecated identifiers for a license with an exception, along with the
+/// license and exception that replaced them
+const DEPRECATED_WITH: &[(&str, &str, &str)] = &[
+    ("GPL-2.0-with-autoconf-exception",  "GPL-2.0-only", "Autoconf-exception-2.0"),
+    ("GPL-2.0-with-bison-exception",     "GPL-2.0-only", "Bison-exception-2.2"),
+    ("GPL-2.0-with-classpath-exception", "GPL-2.0-only", "Classpath-exception-2.0"),
+    ("GPL-2.0-with-font-exception",      "GPL-2.0-only", "Font-exception-2.0"),
+    ("GPL-2.0-with-GCC-exception",       "GPL-2.0-only", "GCC-exception-2.0"),
+    ("GPL-3.0-with-autoconf-exception",  "GPL-3.0-only", "Autoconf-exception-3.0"),
+    ("GPL-3.0-with-GCC-exception",       "GPL-3.0-only", "GCC-exception-3.1"),
+];
+
+fn find_license(id: &str) -> Option<&'static LicenseId> {
+    spdx::LICENSES.iter().find(|license| license.id.eq_ignore_ascii_case(id))
+}
+
+fn find_exception(id: &str) -> Option<&'static ExceptionId> {
+    spdx::EXCEPTIONS.iter().find(|exception| exception.id.eq_ignore_ascii_case(id))
+}
+
 impl FromStr for License {
     type Err = Void;
     fn from_str(s: &str) -> Result<License, Void> {
-        Ok(match s.trim() {
-            "Unlicense"                       => License::Unlicense,
-            "0BSD"                            => License::BSD_0_Clause,
-            "CC0-1.0"                         => License::CC0_1_0,
-            "MIT"                             => License::MIT,
-            "X11"                             => License::X11,
-            "BSD-2-Clause"                    => License::BSD_2_Clause,
-            "BSD-3-Clause"                    => License::BSD_3_Clause,
-            "Apache-2.0"                      => License::Apache_2_0,
-            "LGPL-2.0-only"     | "LGPL-2.0"  => License::LGPL_2_0,
-            "LGPL-2.1-only"     | "LGPL-2.1"  => License::LGPL_2_1,
-            "LGPL-2.1-or-later" | "LGPL-2.1+" => License::LGPL_2_1Plus,
-            "LGPL-3.0-only"     | "LGPL-3.0"  => License::LGPL_3_0,
-            "LGPL-3.0-or-later" | "LGPL-3.0+" => License::LGPL_3_0Plus,
-            "MPL-1.1"                         => License::MPL_1_1,
-            "MPL-2.0"                         => License::MPL_2_0,
-            "GPL-2.0-only"      | "GPL-2.0"   => License::GPL_2_0,
-            "GPL-2.0-or-later"  | "GPL-2.0+"  => License::GPL_2_0Plus,
-            "GPL-3.0-only"      | "GPL-3.0"   => License::GPL_3_0,
-            "GPL-3.0-or-later"  | "GPL-3.0+"  => License::GPL_3_0Plus,
-            "AGPL-3.0-only"     | "AGPL-3.0"  => License::AGPL_3_0,
-            "AGPL-3.0-or-later" | "AGPL-3.0+" => License::AGPL_3_0Plus,
-            s if s.contains('/') || s.contains(" OR ") => {
-                let mut licenses = s.split('/')
-                    .flat_map(|s| s.split(" OR "))
-                    .map(str::parse)
-                    .map(Result::unwrap)
-                    .collect::<Vec<License>>();
-                licenses.sort();
-                License::Multiple(licenses)
-            },
-            s => License::Custom(s.to_owned()),
-        })
+        // Anything that doesn't parse as an expression is kept verbatim so it
+        // can still be displayed to the user
+        Ok(Parser::new(s).parse().unwrap_or_else(|_| License::Custom(s.trim().to_owned())))
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Token<'a> {
+    Open,
+    Close,
+    And,
+    Or,
+    With,
+    Id(&'a str),
+}
+
+fn tokenize(s: &str) -> Vec<Token> {
+    fn word(s: &str) -> Token {
+        match s {
+            "AND" | "and" => Token::And,
+            "OR" | "or" => Token::Or,
+            "WITH" | "with" => Token::With,
+            s => Token::Id(s),
+        }
+    }
+
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in s.char_indices() {
+        if c == '(' || c == ')' || c == '/' || c.is_whitespace() {
+            if let Some(start) = start.take() {
+                tokens.push(word(&s[start..i]));
+            }
+            match c {
+                '(' => tokens.push(Token::Open),
+                ')' => tokens.push(Token::Close),
+                // Legacy crates.io separator, treated the same as `OR`
+                '/' => tokens.push(Token::Or),
+                _ => (),
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(start) = start {
+        tokens.push(word(&s[start..]));
+    }
+    tokens
+}
+
+/// Recursive descent parser for SPDX license expressions, in order of
+/// increasing precedence operators are `OR`, `AND` and `WITH`
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Parser<'a> {
+        Parser { tokens: tokenize(s), position: 0 }
+    }
+
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.position).cloned()
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.position += 1;
+        token
+    }
+
+    fn parse(mut self) -> Result<License, String> {
+        let license = self.or()?;
+        match self.peek() {
+            None => Ok(license),
+            Some(token) => Err(format!("unexpected {:?}", token)),
+        }
+    }
+
+    fn or(&mut self) -> Result<License, String> {
+        let mut licenses = vec![self.and()?];
+        while let Some(Token::Or) = self.peek() {
+            self.next();
+            licenses.push(self.and()?);
+        }
+        Ok(License::or(licenses))
+    }
+
+    fn and(&mut self) -> Result<License, String> {
+        let mut licenses = vec![self.with()?];
+        while let Some(Token::And) = self.peek() {
+            self.next();
+            licenses.push(self.with()?);
+        }
+        Ok(License::and(licenses))
+    }
+
+    fn with(&mut self) -> Result<License, String> {
+        let license = self.primary()?;
+        if let Some(Token::With) = self.peek() {
+            self.next();
+            match self.next() {
+                Some(Token::Id(exception)) => {
+                    // A deprecated identifier may already include one
+                    let simple = match license {
+                        License::With(..) => false,
+                        ref license => !license.is_compound(),
+                    };
+                    if !simple {
+                        return Err("exceptions can only apply to a single license".to_owned());
+                    }
+                    let exception = exception.parse().expect("infallible");
+                    Ok(License::With(Box::new(license), exception))
+                }
+                token => Err(format!("expected exception, found {:?}", token)),
+            }
+        } else {
+            Ok(license)
+        }
+    }
+
+    fn primary(&mut self) -> Result<License, String> {
+        match self.next() {
+            Some(Token::Open) => {
+                let license = self.or()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(license),
+                    token => Err(format!("expected ')', found {:?}", token)),
+                }
+            }
+            Some(Token::Id(id)) => Ok(License::simple(id)),
+            token => Err(format!("expected license, found {:?}", token)),
+        }
     }
 }
 
 impl fmt::Display for License {
     fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            License::Unlicense     => write!(w, "Unlicense"),
-            License::BSD_0_Clause  => write!(w, "0BSD"),
-            License::CC0_1_0       => write!(w, "CC0-1.0"),
-            License::MIT           => write!(w, "MIT"),
-            License::X11           => write!(w, "X11"),
-            License::BSD_2_Clause  => write!(w, "BSD-2-Clause"),
-            License::BSD_3_Clause  => write!(w, "BSD-3-Clause"),
-            License::Apache_2_0    => write!(w, "Apache-2.0"),
-            License::LGPL_2_0      => write!(w, "LGPL-2.0-only"),
-            License::LGPL_2_1      => write!(w, "LGPL-2.1-only"),
-            License::LGPL_2_1Plus  => write!(w, "LGPL-2.1-or-later"),
-            License::LGPL_3_0      => write!(w, "LGPL-3.0-only"),
-            License::LGPL_3_0Plus  => write!(w, "LGPL-3.0-or-later"),
-            License::MPL_1_1       => write!(w, "MPL-1.1"),
-            License::MPL_2_0       => write!(w, "MPL-2.0"),
-            License::GPL_2_0       => write!(w, "GPL-2.0-only"),
-            License::GPL_2_0Plus   => write!(w, "GPL-2.0-or-later"),
-            License::GPL_3_0       => write!(w, "GPL-3.0-only"),
-            License::GPL_3_0Plus   => write!(w, "GPL-3.0-or-later"),
-            License::AGPL_3_0      => write!(w, "AGPL-3.0-only"),
-            License::AGPL_3_0Plus  => write!(w, "AGPL-3.0-or-later"),
+            License::Spdx(license) => write!(w, "{}", license.id),
             License::Custom(ref s) => write!(w, "{}", s),
             License::File(ref f)   => write!(w, "License specified in file ({})", f.to_string_lossy()),
-            License::Multiple(ref ls)   => {
-                write!(w, "{}", ls[0])?;
-                for l in ls.iter().skip(1) {
-                    write!(w, " / {}", l)?;
+            License::Unspecified          => write!(w, "No license specified"),
+            License::With(ref l, ref e) => write!(w, "{} WITH {}", l, e),
+            License::And(ref ls)   => {
+                for (i, l) in ls.iter().enumerate() {
+                    if i > 0 {
+                        write!(w, " AND ")?;
+                    }
+                    if let License::Or(_) = *l {
+                        write!(w, "({})", l)?;
+                    } else {
+                        write!(w, "{}", l)?;
+                    }
                 }
                 Ok(())
             },
-            License::Unspecified          => write!(w, "No license specified"),
+            License::Or(ref ls)   => {
+                for (i, l) in ls.iter().enumerate() {
+                    if i > 0 {
+                        write!(w, " OR ")?;
+                    }
+                    write!(w, "{}", l)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+impl Exception {
+    /// Whether this exception allows linking the licensed code into works
+    /// under any other license
+    pub fn permits_linking(&self) -> bool {
+        match *self {
+            Exception::Spdx(exception) => match exception.id {
+                "Classpath-exception-2.0"
+                    | "GCC-exception-2.0"
+                    | "GCC-exception-3.1"
+                    | "LGPL-3.0-linking-exception"
+                    | "LLVM-exception"
+                    | "Linux-syscall-note"
+                    | "OCaml-LGPL-linking-exception"
+                    | "WxWindows-exception-3.1" => true,
+                _ => false,
+            },
+            Exception::Custom(_) => false,
+        }
+    }
+
+    pub fn is_custom(&self) -> bool {
+        match *self {
+            Exception::Custom(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn template(&self) -> Option<&'static str> {
+        Some(match *self {
+            Exception::Spdx(exception) => match exception.id {
+                "Autoconf-exception-3.0"        => include_str!("exceptions/Autoconf-exception-3.0"),
+                "Bison-exception-2.2"           => include_str!("exceptions/Bison-exception-2.2"),
+                "Classpath-exception-2.0"       => include_str!("exceptions/Classpath-exception-2.0"),
+                "GCC-exception-3.1"             => include_str!("exceptions/GCC-exception-3.1"),
+                "LGPL-3.0-linking-exception"    => include_str!("exceptions/LGPL-3.0-linking-exception"),
+                "LLVM-exception"                => include_str!("exceptions/LLVM-exception"),
+                "Linux-syscall-note"            => include_str!("exceptions/Linux-syscall-note"),
+                "OCaml-LGPL-linking-exception"  => include_str!("exceptions/OCaml-LGPL-linking-exception"),
+                "WxWindows-exception-3.1"       => include_str!("exceptions/WxWindows-exception-3.1"),
+                _                               => { return None }
+            },
+            Exception::Custom(_) => { return None }
+        })
+    }
+}
+
+impl FromStr for Exception {
+    type Err = Void;
+    fn from_str(s: &str) -> Result<Exception, Void> {
+        let s = s.trim();
+        Ok(find_exception(s).map(Exception::Spdx).unwrap_or_else(|| Exception::Custom(s.to_owned())))
+    }
+}
+
+impl fmt::Display for Exception {
+    fn fmt(&self, w: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Exception::Spdx(exception)     => write!(w, "{}", exception.id),
+            Exception::Custom(ref s)       => write!(w, "{}", s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use void::ResultVoidExt;
+
+    use super::{Exception, License};
+
+    fn parse(s: &str) -> License {
+        s.parse().void_unwrap()
+    }
+
+    fn id(license: &License) -> &'static str {
+        match *license {
+            License::Spdx(license) => license.id,
+            ref license => panic!("expected an SPDX license, found {:?}", license),
+        }
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(id(&parse("MIT")), "MIT");
+        assert_eq!(id(&parse(" apache-2.0 ")), "Apache-2.0");
+        assert_eq!(parse("Foo-License"), License::Custom("Foo-License".to_owned()));
+    }
+
+    #[test]
+    fn deprecated_gnu_ids() {
+        assert_eq!(id(&parse("GPL-2.0")), "GPL-2.0-only");
+        assert_eq!(id(&parse("GPL-2.0+")), "GPL-2.0-or-later");
+        assert_eq!(parse("LGPL-2.1+"), parse("LGPL-2.1-or-later"));
+    }
+
+    #[test]
+    fn deprecated_ids_with_exceptions() {
+        assert_eq!(parse("GPL-2.0-with-classpath-exception"), parse("GPL-2.0-only WITH Classpath-exception-2.0"));
+        assert_eq!(parse("gpl-3.0-with-gcc-exception"), parse("GPL-3.0-only WITH GCC-exception-3.1"));
+        match parse("GPL-2.0-with-classpath-exception OR MIT") {
+            License::Or(ref licenses) => assert!(licenses.iter().any(|license| match *license {
+                License::With(ref license, Exception::Spdx(exception)) => {
+                    id(license) == "GPL-2.0-only" && exception.id == "Classpath-exception-2.0"
+                }
+                _ => false,
+            })),
+            ref license => panic!("expected OR, found {:?}", license),
+        }
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        let license = parse("MIT OR Apache-2.0 AND BSD-3-Clause");
+        assert_eq!(license, parse("MIT OR (Apache-2.0 AND BSD-3-Clause)"));
+        assert_ne!(license, parse("(MIT OR Apache-2.0) AND BSD-3-Clause"));
+        match license {
+            License::Or(ref licenses) => {
+                assert_eq!(licenses.len(), 2);
+                assert!(licenses.contains(&parse("MIT")));
+                assert!(licenses.contains(&parse("Apache-2.0 AND BSD-3-Clause")));
+            }
+            ref license => panic!("expected OR, found {:?}", license),
+        }
+    }
+
+    #[test]
+    fn with_binds_tighter_than_and() {
+        match parse("Apache-2.0 WITH LLVM-exception AND MIT") {
+            License::And(ref licenses) => {
+                assert_eq!(licenses.len(), 2);
+                assert!(licenses.contains(&parse("MIT")));
+                assert!(licenses.iter().any(|license| match *license {
+                    License::With(ref license, Exception::Spdx(exception)) => {
+                        id(license) == "Apache-2.0" && exception.id == "LLVM-exception"
+                    }
+                    _ => false,
+                }));
+            }
+            ref license => panic!("expected AND, found {:?}", license),
+        }
+    }
+
+    #[test]
+    fn parentheses() {
+        match parse("(MIT OR Apache-2.0) AND (BSD-3-Clause OR ISC)") {
+            License::And(ref licenses) => {
+                assert_eq!(licenses.len(), 2);
+                assert!(licenses.iter().all(|license| match *license {
+                    License::Or(ref licenses) => licenses.len() == 2,
+                    _ => false,
+                }));
+            }
+            ref license => panic!("expected AND, found {:?}", license),
+        }
+        assert_eq!(parse("((MIT))"), parse("MIT"));
+    }
+
+    #[test]
+    fn equivalent_expressions_compare_equal() {
+        assert_eq!(parse("MIT OR Apache-2.0"), parse("Apache-2.0 OR MIT"));
+        assert_eq!(parse("MIT OR (Apache-2.0 OR ISC)"), parse("ISC OR MIT OR Apache-2.0"));
+        assert_eq!(parse("MIT AND MIT"), parse("MIT"));
+    }
+
+    #[test]
+    fn legacy_slash_separator() {
+        assert_eq!(parse("MIT/Apache-2.0"), parse("MIT OR Apache-2.0"));
+        assert_eq!(parse("MIT / Apache-2.0"), parse("MIT OR Apache-2.0"));
+        assert_eq!(parse("mit or apache-2.0"), parse("MIT OR Apache-2.0"));
+    }
+
+    #[test]
+    fn malformed() {
+        let malformed = &[
+            "MIT OR", "AND MIT", "(MIT", "MIT)", "MIT Apache-2.0", "MIT WITH", "",
+            "(MIT OR ISC) WITH LLVM-exception",
+            "GPL-2.0-with-GCC-exception WITH LLVM-exception",
+        ];
+        for s in malformed {
+            assert_eq!(parse(s), License::Custom(s.trim().to_owned()), "parsing {:?}", s);
         }
     }
 }
@@ -1,6 +1,15 @@
-use cargo::core::Package;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
 
+use cargo::core::{Package, PackageId};
+use cargo::{Config, CargoResult};
+
+use clarification::{Clarification, Hash};
+use discovery::SpdxHeader;
 use license::License;
+use settings::{self, Settings};
 
 pub trait Licensed {
     fn license(&self) -> License;
@@ -19,3 +28,147 @@ impl Licensed for Package {
             .unwrap_or_default()
     }
 }
+
+struct Clarified {
+    license: License,
+    texts: Vec<(PathBuf, String)>,
+}
+
+/// The licensing information actually used for a set of packages, their own
+/// metadata with any clarifications from `lichking.toml` applied, falling back
+/// to the SPDX headers in their source for packages that don't declare one
+pub struct Licenses {
+    clarified: HashMap<PackageId, Clarified>,
+    detected: HashMap<PackageId, License>,
+}
+
+impl Licenses {
+    pub fn new(packages: &[Package], settings: &Settings, config: &Config) -> CargoResult<Licenses> {
+        let mut clarified = HashMap::new();
+        let mut detected = HashMap::new();
+        for package in packages {
+            if let Some(clarification) = settings.clarifications.iter().find(|clarification| clarification.matches(package)) {
+                if let Some(texts) = read_license_files(package, clarification, config)? {
+                    clarified.insert(package.package_id().clone(), Clarified {
+                        license: clarification.license.clone(),
+                        texts,
+                    });
+                }
+            }
+
+            let headers = settings.discovery.find_spdx_headers(package)?;
+            if headers.is_empty() {
+                continue;
+            }
+            let license = match clarified.get(package.package_id()) {
+                Some(clarified) => clarified.license.clone(),
+                None => package.license(),
+            };
+            match license {
+                License::Unspecified => {
+                    detected.insert(package.package_id().clone(), combine(&headers));
+                }
+                License::File(_) => (),
+                license => check_headers(package, &license, &headers, config)?,
+            }
+        }
+        Ok(Licenses { clarified, detected })
+    }
+
+    pub fn license(&self, package: &Package) -> License {
+        match self.clarified.get(package.package_id()) {
+            Some(clarified) => clarified.license.clone(),
+            None => match package.license() {
+                License::Unspecified => self.detected.get(package.package_id()).cloned().unwrap_or_default(),
+                license => license,
+            },
+        }
+    }
+
+    /// The license files named by the clarification for this package, if it
+    /// names any
+    pub fn texts(&self, package: &Package) -> Option<&[(PathBuf, String)]> {
+        self.clarified.get(package.package_id())
+            .map(|clarified| &clarified.texts[..])
+            .filter(|texts| !texts.is_empty())
+    }
+}
+
+/// Reads the files named by a clarification, if any of them are missing or
+/// their contents no longer match the pinned hash the clarification is for a
+/// different release of the package and is ignored
+fn read_license_files(package: &Package, clarification: &Clarification, config: &Config) -> CargoResult<Option<Vec<(PathBuf, String)>>> {
+    let mut texts = Vec::new();
+    for file in &clarification.license_files {
+        let path = package.root().join(&file.path);
+        let mut text = String::new();
+        if let Err(err) = File::open(&path).and_then(|mut f| f.read_to_string(&mut text)) {
+            config.shell().warn(format!("ignoring clarification for {} {} from {}, could not read {}: {}", package.name(), package.version(), settings::FILE_NAME, file.path.display(), err))?;
+            return Ok(None);
+        }
+        match file.check(&text) {
+            Hash::Matches => (),
+            Hash::Unpinned(hash) => {
+                config.shell().warn(format!("clarification for {} in {} does not pin {}, add `hash = \"{}\"`", package.name(), settings::FILE_NAME, file.path.display(), hash))?;
+            }
+            Hash::Changed { expected, found } => {
+                config.shell().warn(format!("ignoring clarification for {} {} from {}, {} has changed", package.name(), package.version(), settings::FILE_NAME, file.path.display()))?;
+                config.shell().warn(format!("    expected hash {}", expected))?;
+                config.shell().warn(format!("    found hash    {}", found))?;
+                return Ok(None);
+            }
+        }
+        texts.push((file.path.clone(), text));
+    }
+    Ok(Some(texts))
+}
+
+/// The license of a package that only has SPDX headers to go by, every
+/// license used in any of its files
+fn combine(headers: &[SpdxHeader]) -> License {
+    License::and(headers.iter().map(|header| header.license.clone()).collect())
+}
+
+/// Warns about source files with SPDX headers for licenses the package doesn't
+/// declare
+fn check_headers(package: &Package, license: &License, headers: &[SpdxHeader], config: &Config) -> CargoResult<()> {
+    let declared = license.simple_licenses();
+    let mut disagreeing: Vec<&SpdxHeader> = Vec::new();
+    for header in headers {
+        let disagrees = header.license.simple_licenses().iter().any(|license| !declared.contains(license));
+        if disagrees && !disagreeing.iter().any(|seen| seen.license == header.license) {
+            disagreeing.push(header);
+        }
+    }
+    if !disagreeing.is_empty() {
+        config.shell().warn(format!("{} {} declares license {} but has SPDX headers that disagree", package.name(), package.version(), license))?;
+        for header in disagreeing {
+            config.shell().warn(format!("    {} in {}", header.license, header.path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use void::ResultVoidExt;
+
+    use discovery::SpdxHeader;
+    use license::License;
+
+    use super::combine;
+
+    fn header(path: &str, license: &str) -> SpdxHeader {
+        SpdxHeader { path: PathBuf::from(path), license: license.parse().void_unwrap() }
+    }
+
+    #[test]
+    fn combined_headers_are_normalized() {
+        let headers = [header("src/lib.rs", "MIT OR Apache-2.0"), header("src/a.rs", "MIT"), header("src/b.rs", "Zlib AND MIT")];
+        assert_eq!(combine(&headers), "(Apache-2.0 OR MIT) AND MIT AND Zlib".parse::<License>().void_unwrap());
+        assert_eq!(combine(&headers[1..]), "MIT AND Zlib".parse::<License>().void_unwrap());
+        assert_eq!(combine(&headers[1..2]), "MIT".parse::<License>().void_unwrap());
+    }
+}
@@ -1,38 +1,203 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::path::PathBuf;
 
-use cargo::core::Package;
+use cargo::core::{Package, SourceId};
+use cargo::core::dependency::Kind;
 use cargo::CargoResult;
+use serde_json;
 
-use licensed::Licensed;
-use options::By;
+use discovery::{Confidence, Discovery};
+use license::License;
+use licensed::Licenses;
+use load::Graph;
+use options::{By, Format};
+
+#[derive(Serialize)]
+struct Source {
+    kind: &'static str,
+    url: String,
+}
+
+#[derive(Serialize)]
+struct LicenseText {
+    path: PathBuf,
+    score: Option<f32>,
+    confidence: Confidence,
+    /// The text found in place of the variable parts of the license, like
+    /// the copyright holder
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    variables: BTreeMap<String, String>,
+}
+
+/// A license file for a sub-component vendored into the package
+#[derive(Serialize)]
+struct VendoredLicense {
+    path: PathBuf,
+}
+
+/// The licensing information about a single package in the JSON output
+#[derive(Serialize)]
+struct Entry {
+    name: String,
+    version: String,
+    source: Source,
+    /// How the package is used, `normal`, `build` or `dev`
+    kinds: Vec<&'static str>,
+    /// The `license` field from the package's metadata
+    license: Option<String>,
+    /// The parsed SPDX expression, after applying clarifications
+    expression: Option<String>,
+    license_file: Option<String>,
+    license_texts: Vec<LicenseText>,
+    /// The Apache-2.0 `NOTICE` file, if there is one
+    notice: Option<PathBuf>,
+    vendored_licenses: Vec<VendoredLicense>,
+}
+
+#[derive(Serialize)]
+struct LicenseGroup {
+    license: String,
+    packages: Vec<Entry>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Document {
+    Licenses(Vec<LicenseGroup>),
+    Packages(Vec<Entry>),
+}
+
+pub fn run(graph: &Graph, licenses: &Licenses, discovery: &Discovery, by: By, format: Format) -> CargoResult<()> {
+    let mut packages = graph.packages.clone();
+    packages.sort_by(|p1, p2| (p1.name(), p1.version()).cmp(&(p2.name(), p2.version())));
 
-pub fn run(mut packages: Vec<Package>, by: By) -> CargoResult<()> {
     match by {
         By::License => {
             let mut license_to_packages = HashMap::new();
 
             for package in packages {
                 license_to_packages
-                    .entry(package.license())
+                    .entry(licenses.license(&package))
                     .or_insert_with(Vec::new)
                     .push(package);
             }
 
-            let mut license_to_packages = license_to_packages.iter().collect::<Vec<_>>();
-            license_to_packages.sort_by_key(|&(license, _)| license);
+            let mut license_to_packages = license_to_packages.into_iter().collect::<Vec<_>>();
+            license_to_packages.sort_by(|&(ref l1, _), &(ref l2, _)| l1.cmp(l2));
 
-            for (license, packages) in license_to_packages {
-                let packages = packages.iter().map(|package| package.name().as_str()).collect::<Vec<&str>>().join(", ");
-                println!("{}: {}", license, packages);
+            match format {
+                Format::Text => {
+                    for (license, packages) in license_to_packages {
+                        let packages = packages.iter().map(|package| package.name().as_str()).collect::<Vec<&str>>().join(", ");
+                        println!("{}: {}", license, packages);
+                    }
+                }
+                Format::Json => {
+                    let groups = license_to_packages.into_iter().map(|(license, packages)| Ok(LicenseGroup {
+                        license: license.to_string(),
+                        packages: packages.iter().map(|package| entry(package, graph, licenses, discovery)).collect::<CargoResult<_>>()?,
+                    })).collect::<CargoResult<_>>()?;
+                    print_json(&Document::Licenses(groups))?;
+                }
             }
         }
         By::Crate => {
-            packages.sort_by_key(|package| package.name().to_owned());
-            for package in packages {
-                println!("{}: {}", package.name(), package.license());
+            match format {
+                Format::Text => {
+                    for package in packages {
+                        println!("{}: {}", package.name(), licenses.license(&package));
+                    }
+                }
+                Format::Json => {
+                    let entries = packages.iter().map(|package| entry(package, graph, licenses, discovery)).collect::<CargoResult<_>>()?;
+                    print_json(&Document::Packages(entries))?;
+                }
             }
         }
     }
 
     Ok(())
 }
+
+fn print_json(document: &Document) -> CargoResult<()> {
+    serde_json::to_writer_pretty(io::stdout(), document)?;
+    println!();
+    Ok(())
+}
+
+fn entry(package: &Package, graph: &Graph, licenses: &Licenses, discovery: &Discovery) -> CargoResult<Entry> {
+    let metadata = package.manifest().metadata();
+    let license = licenses.license(package);
+
+    let expression = match license {
+        License::Unspecified | License::File(_) => None,
+        ref license => Some(license.to_string()),
+    };
+
+    Ok(Entry {
+        name: package.name().to_string(),
+        version: package.version().to_string(),
+        source: source(package.package_id().source_id()),
+        kinds: graph.kinds(package.package_id()).iter().map(|kind| match *kind {
+            Kind::Normal => "normal",
+            Kind::Build => "build",
+            Kind::Development => "dev",
+        }).collect(),
+        license: metadata.license.clone(),
+        expression,
+        license_file: metadata.license_file.clone(),
+        license_texts: license_texts(package, licenses, discovery, &license)?,
+        notice: discovery.find_notice(package)?.map(|notice| notice.path),
+        vendored_licenses: discovery.find_vendored_license_texts(package)?
+            .into_iter()
+            .map(|file| VendoredLicense { path: file.path })
+            .collect(),
+    })
+}
+
+fn source(source_id: &SourceId) -> Source {
+    let kind = if source_id.is_registry() {
+        "registry"
+    } else if source_id.is_git() {
+        "git"
+    } else if source_id.is_path() {
+        "path"
+    } else {
+        "other"
+    };
+    Source {
+        kind,
+        url: source_id.url().to_string(),
+    }
+}
+
+/// The same candidates `bundle` would choose from
+fn license_texts(package: &Package, licenses: &Licenses, discovery: &Discovery, license: &License) -> CargoResult<Vec<LicenseText>> {
+    if let Some(texts) = licenses.texts(package) {
+        return Ok(texts.iter().map(|&(ref path, _)| LicenseText {
+            path: path.clone(),
+            score: None,
+            confidence: Confidence::Confident,
+            variables: BTreeMap::new(),
+        }).collect());
+    }
+
+    let mut texts = Vec::new();
+    if let License::Unspecified = *license {
+        return Ok(texts);
+    }
+    if let Some(text) = discovery.find_generic_license_text(package, license)? {
+        texts.push(LicenseText { path: text.path, score: text.score, confidence: text.confidence, variables: text.variables });
+    }
+    for license in license.simple_licenses() {
+        for text in discovery.find_license_text(package, license)? {
+            // A file may match more than one way, only list it once
+            if texts.iter().any(|found: &LicenseText| found.path == text.path) {
+                continue;
+            }
+            texts.push(LicenseText { path: text.path, score: text.score, confidence: text.confidence, variables: text.variables });
+        }
+    }
+    Ok(texts)
+}
@@ -1,60 +1,286 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use cargo::core::dependency::Kind;
-use cargo::core::{ Package, Workspace };
+use cargo::core::dependency::{Kind, Platform};
+use cargo::core::{ Package, PackageId, PackageIdSpec, PackageSet, Resolve, Workspace };
+use cargo::core::resolver::Method;
+use cargo::core::{ Dependency, FeatureValue };
 use cargo::ops;
+use cargo::util::Cfg;
 use cargo::util::important_paths::find_root_manifest_for_wd;
 use cargo::{ Config, CargoResult };
 
-use options::SelectedPackage;
+use options::{Resolution, SelectedPackage};
 
-pub fn resolve_roots(
-        config: &Config,
-        package: SelectedPackage) -> CargoResult<Vec<Package>> {
-    let root_manifest = find_root_manifest_for_wd(config.cwd())?;
-    let workspace = Workspace::new(&root_manifest, config)?;
+pub fn workspace<'cfg>(
+        config: &'cfg Config,
+        resolution: &Resolution) -> CargoResult<Workspace<'cfg>> {
+    let root_manifest = match resolution.manifest_path {
+        Some(ref path) => config.cwd().join(path),
+        None => find_root_manifest_for_wd(config.cwd())?,
+    };
+    Workspace::new(&root_manifest, config)
+}
+
+/// The dependencies of the selected packages in a workspace, each resolved
+/// the same way a build of it would be and then walked from any of the roots
+pub struct Resolved<'cfg> {
+    pub roots: Vec<Package>,
+    /// The packages each of the roots was resolved with, in the same order
+    resolves: Vec<(PackageSet<'cfg>, Resolve)>,
+    dep_kinds: Vec<Kind>,
+    targets: Option<Vec<(String, Vec<Cfg>)>>,
+    /// Build scripts and proc-macros, and everything they depend on, are
+    /// built for the host instead of the targets
+    host: Option<(String, Vec<Cfg>)>,
+}
 
-    Ok(match package {
-        SelectedPackage::All => {
-            workspace.members().cloned().collect()
+pub fn resolve<'cfg>(
+        workspace: &Workspace<'cfg>,
+        package: SelectedPackage,
+        resolution: &Resolution) -> CargoResult<Resolved<'cfg>> {
+    // Resolve the same way a build of the roots would, so that optional
+    // dependencies are only included if a feature activates them
+    let features = Method::split_features(&resolution.features);
+    let method = Method::Required {
+        dev_deps: resolution.dep_kinds.contains(&Kind::Development),
+        features: &features,
+        all_features: resolution.all_features,
+        uses_default_features: !resolution.no_default_features,
+    };
+
+    let mut roots = Vec::new();
+    let mut resolves = Vec::new();
+    match package {
+        SelectedPackage::Specific(ref spec) if !workspace.members().any(|member| spec.matches(member.package_id())) => {
+            // A package outside of the workspace is found among the
+            // dependencies of all the members, like cargo doesn't allow
+            // choosing its features
+            if !resolution.features.is_empty() || resolution.all_features || resolution.no_default_features {
+                bail!("cannot specify features for packages outside of the workspace");
+            }
+            let specs = workspace.members()
+                .map(|member| PackageIdSpec::from_package_id(member.package_id()))
+                .collect::<Vec<_>>();
+            let (packages, resolve) = ops::resolve_ws_with_method(workspace, None, method, &specs)?;
+            roots.push(packages.get_one(spec.query(packages.package_ids())?)?.clone());
+            resolves.push((packages, resolve));
         }
-        SelectedPackage::Default => {
-            vec![workspace.current()?.clone()]
+        package => {
+            let members = match package {
+                SelectedPackage::All => workspace.members().collect::<Vec<_>>(),
+                SelectedPackage::Default => vec![workspace.current()?],
+                SelectedPackage::Specific(spec) => workspace.members().filter(|member| spec.matches(member.package_id())).collect(),
+            };
+            // Cargo only applies features to the current package of a
+            // workspace, so each member is resolved as the current package
+            for member in members {
+                let current = Workspace::new(member.manifest_path(), workspace.config())?;
+                let spec = PackageIdSpec::from_package_id(member.package_id());
+                resolves.push(ops::resolve_ws_with_method(&current, None, method, &[spec])?);
+                roots.push(member.clone());
+            }
         }
-        SelectedPackage::Specific(spec) => {
-            let (packages, _) = ops::resolve_ws(&workspace)?;
-            let package_id = spec.query(packages.package_ids())?;
-            vec![packages.get_one(package_id)?.clone()]
+    }
+
+    let targets = match resolution.targets {
+        Some(ref targets) => Some(targets.iter()
+            .map(|target| Ok((target.clone(), target_cfg(workspace.config(), target)?)))
+            .collect::<CargoResult<Vec<_>>>()?),
+        None => None,
+    };
+    let host = match resolution.targets {
+        Some(_) => {
+            let host = workspace.config().rustc(None)?.host.clone();
+            let cfg = target_cfg(workspace.config(), &host)?;
+            Some((host, cfg))
         }
+        None => None,
+    };
+
+    Ok(Resolved {
+        roots,
+        resolves,
+        dep_kinds: resolution.dep_kinds.clone(),
+        targets,
+        host,
     })
 }
 
-pub fn resolve_packages<'a, I: IntoIterator<Item=&'a Package>>(
-        config: &Config,
-        roots: I) -> CargoResult<Vec<Package>> {
-    let root_manifest = find_root_manifest_for_wd(config.cwd())?;
-    let workspace = Workspace::new(&root_manifest, config)?;
-
-    let (packages, resolve) = ops::resolve_ws(&workspace)?;
-
-    let mut result = HashSet::new();
-    let mut to_check = roots.into_iter().map(|p| p.package_id()).collect::<Vec<_>>();
-    while let Some(id) = to_check.pop() {
-        if let Ok(package) = packages.get_one(id) {
-            if result.insert(package) {
-                let deps = resolve.deps_not_replaced(id);
-                for dep_id in deps {
-                    let dep = package.dependencies().iter()
-                        .find(|d| d.matches_id(dep_id))
-                        .unwrap_or_else(|| panic!("Looking up a packages dependency in the package failed, failed to find '{}' in '{}'", dep_id, id));
-                    if let Kind::Normal = dep.kind() {
-                        let dep_id = resolve.replacement(dep_id).unwrap_or(dep_id);
-                        to_check.push(dep_id);
+/// A dependency edge that was followed while resolving
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Edge {
+    pub id: PackageId,
+    pub kind: Kind,
+    /// The feature of the depending package that enabled an optional
+    /// dependency
+    pub feature: Option<String>,
+}
+
+/// The packages reachable from a set of roots, along with the dependency
+/// edges that were followed to reach them
+pub struct Graph {
+    pub roots: Vec<PackageId>,
+    pub packages: Vec<Package>,
+    kinds: HashMap<PackageId, Vec<Kind>>,
+    dependencies: HashMap<PackageId, Vec<Edge>>,
+}
+
+impl Graph {
+    pub fn dependencies(&self, id: &PackageId) -> &[Edge] {
+        self.dependencies.get(id).map(|deps| &deps[..]).unwrap_or(&[])
+    }
+
+    /// How a package is used by the roots, anything depended on by a build
+    /// dependency is itself only used at build time, and likewise for
+    /// development dependencies
+    pub fn kinds(&self, id: &PackageId) -> &[Kind] {
+        self.kinds.get(id).map(|kinds| &kinds[..]).unwrap_or(&[])
+    }
+
+    /// Whether a package is only used to test the roots, so can't end up in
+    /// what is built from them
+    pub fn is_test_only(&self, id: &PackageId) -> bool {
+        self.kinds(id).iter().all(|kind| *kind == Kind::Development)
+    }
+}
+
+impl<'cfg> Resolved<'cfg> {
+    /// Walks the dependencies of some of the roots
+    pub fn graph<'a, I: IntoIterator<Item=&'a Package>>(&self, roots: I) -> Graph {
+        let roots = roots.into_iter().collect::<Vec<_>>();
+        let mut graph = Graph {
+            roots: roots.iter().map(|root| root.package_id().clone()).collect(),
+            packages: Vec::new(),
+            kinds: HashMap::new(),
+            dependencies: HashMap::new(),
+        };
+        for root in roots {
+            let index = self.roots.iter()
+                .position(|resolved| resolved.package_id() == root.package_id())
+                .expect("walking the dependencies of a package that wasn't resolved");
+            let (ref packages, ref resolve) = self.resolves[index];
+            self.walk(packages, resolve, root, &mut graph);
+        }
+
+        for kinds in graph.kinds.values_mut() {
+            kinds.sort();
+        }
+
+        graph
+    }
+
+    /// Adds the dependencies of a root, as it was resolved, to a graph
+    fn walk(&self, packages: &PackageSet, resolve: &Resolve, root: &Package, graph: &mut Graph) {
+        let mut visited = HashSet::new();
+        let mut to_check = vec![(root.package_id(), Kind::Normal, false)];
+        while let Some((id, kind, for_host)) = to_check.pop() {
+            if let Ok(package) = packages.get_one(id) {
+                if visited.insert((id, kind, for_host)) {
+                    if !graph.kinds.contains_key(id) {
+                        graph.packages.push(package.clone());
+                    }
+                    let kinds = graph.kinds.entry(id.clone()).or_insert_with(Vec::new);
+                    if !kinds.contains(&kind) {
+                        kinds.push(kind);
+                    }
+                    let edges = graph.dependencies.entry(id.clone()).or_insert_with(Vec::new);
+                    let activated = resolve.features(id);
+                    let deps = resolve.deps_not_replaced(id);
+                    for dep_id in deps {
+                        let deps = package.dependencies().iter()
+                            .filter(|d| d.matches_id(dep_id))
+                            .collect::<Vec<_>>();
+                        if deps.is_empty() {
+                            panic!("Looking up a packages dependency in the package failed, failed to find '{}' in '{}'", dep_id, id);
+                        }
+                        for dep in deps {
+                            // The same crate may also be depended on through
+                            // an optional entry that no feature turned on
+                            if dep.is_optional() && !activated.contains(dep.name_in_toml().as_str()) {
+                                continue;
+                            }
+                            // Filter on how the roots use the dependency, so
+                            // that the normal dependencies of a build
+                            // dependency are kept along with it
+                            let dep_kind = if kind == Kind::Normal { dep.kind() } else { kind };
+                            if !self.dep_kinds.contains(&dep_kind) {
+                                continue;
+                            }
+                            // Build dependencies are used by the build script,
+                            // which is run on the host
+                            let dep_for_host = for_host || dep.kind() == Kind::Build;
+                            if let Some(platform) = dep.platform() {
+                                if !self.platform_matches(platform, dep_for_host) {
+                                    continue;
+                                }
+                            }
+                            // Cargo only uses the development dependencies
+                            // of the packages being built
+                            if dep.kind() == Kind::Development && !graph.roots.contains(id) {
+                                continue;
+                            }
+                            let dep_id = resolve.replacement(dep_id).unwrap_or(dep_id);
+                            let edge = Edge {
+                                id: dep_id.clone(),
+                                kind: dep.kind(),
+                                feature: if dep.is_optional() { enabling_feature(resolve, package, dep) } else { None },
+                            };
+                            if !edges.contains(&edge) {
+                                edges.push(edge);
+                            }
+                            let dep_for_host = dep_for_host || packages.get_one(dep_id).map(is_proc_macro).unwrap_or(false);
+                            to_check.push((dep_id, dep_kind, dep_for_host));
+                        }
                     }
                 }
             }
         }
     }
 
-    Ok(result.into_iter().cloned().collect())
+    /// Whether a platform specific dependency is used when building for any
+    /// of the targets, or for the host, every dependency is used when no
+    /// targets were given
+    fn platform_matches(&self, platform: &Platform, for_host: bool) -> bool {
+        match (self.targets.as_ref(), self.host.as_ref()) {
+            (Some(_), Some(&(ref host, ref cfg))) if for_host => platform.matches(host, Some(cfg)),
+            (Some(targets), _) => targets.iter().any(|&(ref target, ref cfg)| platform.matches(target, Some(cfg))),
+            (None, _) => true,
+        }
+    }
+}
+
+/// Finds which of the activated features of a package turned on an
+/// optional dependency, preferring explicit features over the implicit
+/// one named after the dependency
+fn enabling_feature(resolve: &Resolve, package: &Package, dep: &Dependency) -> Option<String> {
+    let name = dep.name_in_toml();
+    let activated = resolve.features(package.package_id());
+    package.summary().features().iter()
+        .filter(|&(feature, _)| activated.contains(feature.as_str()))
+        .find(|&(_, values)| values.iter().any(|value| match *value {
+            FeatureValue::Crate(ref krate) | FeatureValue::CrateFeature(ref krate, _) => *krate == name,
+            FeatureValue::Feature(_) => false,
+        }))
+        .map(|(feature, _)| feature.to_string())
+        .or_else(|| Some(name.to_string()).filter(|name| activated.contains(name)))
+}
+
+/// Whether a package is a proc-macro, which is built for the host along with
+/// its dependencies
+fn is_proc_macro(package: &Package) -> bool {
+    // Of the library targets only proc-macros are built for the host
+    package.targets().iter().any(|target| target.is_lib() && target.for_host())
+}
+
+/// The `cfg` values set when compiling for a target, as reported by rustc
+fn target_cfg(config: &Config, target: &str) -> CargoResult<Vec<Cfg>> {
+    let output = config.rustc(None)?.process()
+        .arg("--print=cfg")
+        .arg("--target").arg(target)
+        .exec_with_output()
+        .map_err(|err| format_err!("failed to get the cfg values for target {}: {}", target, err))?;
+    String::from_utf8(output.stdout)?
+        .lines()
+        .map(|line| line.parse())
+        .collect()
 }
@@ -2,22 +2,38 @@
 
 #[macro_use] extern crate clap;
 extern crate cargo;
+extern crate crypto_hash;
 #[macro_use] extern crate failure;
+extern crate glob;
 extern crate regex;
+extern crate semver;
+extern crate serde;
+#[macro_use] extern crate serde_derive;
+extern crate serde_json;
+extern crate toml;
 extern crate void;
+extern crate walkdir;
 
 mod bundle;
 mod check;
+mod clarification;
+mod compatibility;
 mod discovery;
 mod license;
 mod licensed;
 mod list;
 mod load;
 mod options;
+mod policy;
+mod settings;
+mod spdx;
+mod template;
 mod thirdparty;
+mod why;
 
 use cargo::{Config, CliResult};
 
+use licensed::Licenses;
 use options::{Options, Cmd};
 
 fn main() {
@@ -43,28 +59,44 @@ fn real_main(options: Options, config: &mut Config) -> CliResult {
     config.shell().warn("IANAL: This is not legal advice and is not guaranteed to be correct.")?;
 
     match options.cmd {
-        Cmd::Check { package } => {
+        Cmd::Check { explain, package, resolution } => {
             let mut error = Ok(());
-            let roots = load::resolve_roots(config, package)?;
-            for root in roots {
-                let packages = load::resolve_packages(config, vec![&root])?;
-                if let Err(err) = check::run(&root, packages, config) {
+            let workspace = load::workspace(config, &resolution)?;
+            let settings = settings::load(&workspace)?;
+            let resolved = load::resolve(&workspace, package, &resolution)?;
+            for root in &resolved.roots {
+                let graph = resolved.graph(vec![root]);
+                let licenses = Licenses::new(&graph.packages, &settings, config)?;
+                if let Err(err) = check::run(root, &graph, config, &settings, &licenses, explain) {
                     error = Err(err);
                 }
             }
             error?;
         }
 
-        Cmd::List { by, package } => {
-            let roots = load::resolve_roots(config, package)?;
-            let packages = load::resolve_packages(config, &roots)?;
-            list::run(packages, by)?;
+        Cmd::List { by, format, package, resolution } => {
+            let workspace = load::workspace(config, &resolution)?;
+            let settings = settings::load(&workspace)?;
+            let resolved = load::resolve(&workspace, package, &resolution)?;
+            let graph = resolved.graph(&resolved.roots);
+            let licenses = Licenses::new(&graph.packages, &settings, config)?;
+            list::run(&graph, &licenses, &settings.discovery, by, format)?;
         }
 
-        Cmd::Bundle { variant, package } => {
-            let roots = load::resolve_roots(config, package)?;
-            let packages = load::resolve_packages(config, &roots)?;
-            bundle::run(&roots, packages, config, variant)?;
+        Cmd::Why { spec, package, resolution } => {
+            let workspace = load::workspace(config, &resolution)?;
+            let resolved = load::resolve(&workspace, package, &resolution)?;
+            let graph = resolved.graph(&resolved.roots);
+            why::run(&graph, &spec)?;
+        }
+
+        Cmd::Bundle { variant, fallback_to_template, package, resolution } => {
+            let workspace = load::workspace(config, &resolution)?;
+            let settings = settings::load(&workspace)?;
+            let resolved = load::resolve(&workspace, package, &resolution)?;
+            let graph = resolved.graph(&resolved.roots);
+            let licenses = Licenses::new(&graph.packages, &settings, config)?;
+            bundle::run(&resolved.roots, graph, config, &settings, &licenses, variant, fallback_to_template)?;
         }
 
         Cmd::ThirdParty { full } => {
@@ -3,6 +3,7 @@
 use std::str::FromStr;
 
 use cargo::core::PackageIdSpec;
+use cargo::core::dependency::Kind;
 use clap::{ App, Arg, SubCommand, AppSettings, ArgMatches };
 
 #[derive(Copy, Clone, Debug)]
@@ -11,6 +12,12 @@ pub enum By {
     Crate,
 }
 
+#[derive(Copy, Clone, Debug)]
+pub enum Format {
+    Text,
+    Json,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SelectedPackage {
     All,
@@ -18,6 +25,19 @@ pub enum SelectedPackage {
     Specific(PackageIdSpec),
 }
 
+/// Options controlling which dependencies are resolved
+#[derive(Clone, Debug)]
+pub struct Resolution {
+    pub manifest_path: Option<String>,
+    pub dep_kinds: Vec<Kind>,
+    /// The target triples to resolve for, `None` to include the dependencies
+    /// for every platform
+    pub targets: Option<Vec<String>>,
+    pub features: Vec<String>,
+    pub all_features: bool,
+    pub no_default_features: bool,
+}
+
 #[derive(Clone, Debug)]
 pub enum Bundle {
     Inline {
@@ -26,6 +46,15 @@ pub enum Bundle {
     NameOnly {
         file: Option<String>,
     },
+    CopyrightNotices {
+        file: Option<String>,
+    },
+    Markdown {
+        file: Option<String>,
+    },
+    Html {
+        file: Option<String>,
+    },
     Source {
         file: Option<String>,
     },
@@ -33,20 +62,51 @@ pub enum Bundle {
         file: Option<String>,
         dir: String,
     },
+    Spdx {
+        file: Option<String>,
+        format: SpdxFormat,
+    },
+    CycloneDx {
+        file: Option<String>,
+        format: CycloneDxFormat,
+    },
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum SpdxFormat {
+    TagValue,
+    Json,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum CycloneDxFormat {
+    Json,
+    Xml,
 }
 
 #[derive(Clone, Debug)]
 pub enum Cmd {
     List {
         by: By,
+        format: Format,
         package: SelectedPackage,
+        resolution: Resolution,
     },
     Check {
+        explain: bool,
+        package: SelectedPackage,
+        resolution: Resolution,
+    },
+    Why {
+        spec: PackageIdSpec,
         package: SelectedPackage,
+        resolution: Resolution,
     },
     Bundle {
         variant: Bundle,
+        fallback_to_template: bool,
         package: SelectedPackage,
+        resolution: Resolution,
     },
     ThirdParty {
         full: bool,
@@ -83,6 +143,26 @@ impl By {
     }
 }
 
+impl Format {
+    fn args() -> Vec<Arg<'static, 'static>> {
+        vec![
+            Arg::with_name("format")
+                .long("format")
+                .takes_value(true)
+                .possible_values(&["text", "json"])
+                .default_value("text")
+                .help("Whether to output human readable text or a JSON document"),
+        ]
+    }
+
+    fn from_matches(matches: &ArgMatches) -> Format {
+        matches.value_of("format")
+            .expect("defaulted")
+            .parse()
+            .expect("constrained")
+    }
+}
+
 impl SelectedPackage {
     fn args() -> Vec<Arg<'static, 'static>> {
         vec![
@@ -123,13 +203,70 @@ impl SelectedPackage {
     }
 }
 
+impl Resolution {
+    fn args() -> Vec<Arg<'static, 'static>> {
+        vec![
+            Arg::with_name("manifest-path")
+                .long("manifest-path")
+                .takes_value(true).value_name("PATH")
+                .help("Path to Cargo.toml"),
+            Arg::with_name("dep-kinds")
+                .long("dep-kinds")
+                .takes_value(true).value_name("KINDS")
+                .use_delimiter(true)
+                .possible_values(&["normal", "build", "dev"])
+                .default_value("normal")
+                .help("Comma separated kinds of dependencies to include"),
+            Arg::with_name("target")
+                .long("target")
+                .takes_value(true).value_name("TRIPLE")
+                .multiple(true).number_of_values(1)
+                .help("Only include dependencies used on this target, may be repeated (all targets if not specified or `all`)"),
+            Arg::with_name("features")
+                .long("features")
+                .takes_value(true).value_name("FEATURES")
+                .multiple(true).number_of_values(1)
+                .help("Space-separated list of features to activate"),
+            Arg::with_name("all-features")
+                .long("all-features")
+                .help("Activate all available features"),
+            Arg::with_name("no-default-features")
+                .long("no-default-features")
+                .help("Do not activate the `default` feature"),
+        ]
+    }
+
+    fn from_matches(matches: &ArgMatches) -> Resolution {
+        Resolution {
+            manifest_path: matches.value_of("manifest-path").map(ToOwned::to_owned),
+            dep_kinds: matches.values_of("dep-kinds")
+                .expect("defaulted")
+                .map(|kind| match kind {
+                    "normal" => Kind::Normal,
+                    "build" => Kind::Build,
+                    "dev" => Kind::Development,
+                    kind => panic!("Unexpected dependency kind {}", kind),
+                })
+                .collect(),
+            targets: matches.values_of("target")
+                .map(|targets| targets.map(ToOwned::to_owned).collect::<Vec<_>>())
+                .filter(|targets| !targets.iter().any(|target| target == "all")),
+            features: matches.values_of("features")
+                .map(|features| features.map(ToOwned::to_owned).collect())
+                .unwrap_or_default(),
+            all_features: matches.is_present("all-features"),
+            no_default_features: matches.is_present("no-default-features"),
+        }
+    }
+}
+
 impl Bundle {
     fn args() -> Vec<Arg<'static, 'static>> {
         vec![
             Arg::with_name("variant")
                 .long("variant")
                 .takes_value(true)
-                .possible_values(&["inline", "name-only", "source", "split"])
+                .possible_values(&["inline", "name-only", "copyright-notices", "markdown", "html", "source", "split", "spdx-tag-value", "spdx-json", "cyclonedx-json", "cyclonedx-xml"])
                 .default_value("inline")
                 .requires_if("split", "dir")
                 .help("")
@@ -144,6 +281,23 @@ What sort of bundle to produce:
         Output a single file to location specified by --file containing just
         the name of the license used by each dependency
 
+    copyright-notices:
+        Output a single file to location specified by --file containing the
+        copyright notices found for each dependency, from its license files,
+        the headers of its source files, or failing those its authors
+
+    markdown:
+        Output a single Markdown file to location specified by --file with a
+        table of the dependencies and their licenses, a section for each
+        dependency and the license texts, texts used by more than one
+        dependency are only included once
+
+    html:
+        Output a self-contained HTML page to location specified by --file with
+        the dependencies grouped by license, links to their repositories and
+        homepages, and a collapsible section with each dependency's license
+        texts
+
     source:
         Output a single file to location specified by --file containing Rust
         source with the name and content of the license used by each dependency
@@ -154,6 +308,22 @@ What sort of bundle to produce:
         specified by --dir containing the text of each dependency's license in a
         separate file inside
 
+    spdx-tag-value:
+        Output an SPDX 2.3 document in tag-value format to location specified by
+        --file describing each dependency, its license and the relationships
+        between them, along with the text of any licenses not on the SPDX list
+
+    spdx-json:
+        Like spdx-tag-value but in the SPDX JSON format
+
+    cyclonedx-json:
+        Output a CycloneDX 1.5 bill of materials in JSON format to location
+        specified by --file listing each dependency with its license and the
+        license texts found for it, along with the dependency graph
+
+    cyclonedx-xml:
+        Like cyclonedx-json but in the CycloneDX XML format
+
 \
                 "),
             Arg::with_name("file")
@@ -175,6 +345,15 @@ What sort of bundle to produce:
             "name-only" => Bundle::NameOnly {
                 file: matches.value_of("file").map(ToOwned::to_owned),
             },
+            "copyright-notices" => Bundle::CopyrightNotices {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+            },
+            "markdown" => Bundle::Markdown {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+            },
+            "html" => Bundle::Html {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+            },
             "source" => Bundle::Source {
                 file: matches.value_of("file").map(ToOwned::to_owned),
             },
@@ -182,6 +361,22 @@ What sort of bundle to produce:
                 file: matches.value_of("file").map(ToOwned::to_owned),
                 dir: matches.value_of("dir").expect("required").to_owned(),
             },
+            "spdx-tag-value" => Bundle::Spdx {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+                format: SpdxFormat::TagValue,
+            },
+            "spdx-json" => Bundle::Spdx {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+                format: SpdxFormat::Json,
+            },
+            "cyclonedx-json" => Bundle::CycloneDx {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+                format: CycloneDxFormat::Json,
+            },
+            "cyclonedx-xml" => Bundle::CycloneDx {
+                file: matches.value_of("file").map(ToOwned::to_owned),
+                format: CycloneDxFormat::Xml,
+            },
             variant => panic!("Unexpected variant value {}", variant),
         }
     }
@@ -249,19 +444,46 @@ impl Options {
         vec![
             SubCommand::with_name("check")
                 .about("Check that all dependencies have a compatible license with a package")
+                .args(&[
+                    Arg::with_name("explain")
+                        .long("explain")
+                        .help("Show the dependency chains that include each package with a problem"),
+                ])
                 .args(&SelectedPackage::args())
+                .args(&Resolution::args())
                 .after_help(SelectedPackage::help()),
 
             SubCommand::with_name("list")
                 .about("List licensing of all dependencies")
                 .args(&By::args())
+                .args(&Format::args())
                 .args(&SelectedPackage::args())
+                .args(&Resolution::args())
+                .after_help(SelectedPackage::help()),
+
+            SubCommand::with_name("why")
+                .about("Show every dependency chain that includes a package, shortest first")
+                .args(&[
+                    Arg::with_name("spec")
+                        .required(true)
+                        .takes_value(true).value_name("SPEC")
+                        .validator(|s| PackageIdSpec::parse(&s).map(|_| ()).map_err(|e| e.to_string()))
+                        .help("The package to explain"),
+                ])
+                .args(&SelectedPackage::args())
+                .args(&Resolution::args())
                 .after_help(SelectedPackage::help()),
 
             SubCommand::with_name("bundle")
                 .about("Bundle all dependencies licenses ready for distribution")
                 .args(&Bundle::args())
+                .args(&[
+                    Arg::with_name("fallback-to-template")
+                        .long("fallback-to-template")
+                        .help("Use the standard text of a license for packages that don't include it, marked as synthesised"),
+                ])
                 .args(&SelectedPackage::args())
+                .args(&Resolution::args())
                 .after_help(SelectedPackage::help()),
 
             SubCommand::with_name("thirdparty")
@@ -285,19 +507,32 @@ impl Options {
             cmd: match matches.subcommand() {
                 ("check", Some(matches)) => {
                     Cmd::Check {
+                        explain: matches.is_present("explain"),
                         package: SelectedPackage::from_matches(matches),
+                        resolution: Resolution::from_matches(matches),
                     }
                 }
                 ("list", Some(matches)) => {
                     Cmd::List {
                         by: By::from_matches(matches),
+                        format: Format::from_matches(matches),
                         package: SelectedPackage::from_matches(matches),
+                        resolution: Resolution::from_matches(matches),
+                    }
+                }
+                ("why", Some(matches)) => {
+                    Cmd::Why {
+                        spec: PackageIdSpec::parse(matches.value_of("spec").expect("required")).expect("validated"),
+                        package: SelectedPackage::from_matches(matches),
+                        resolution: Resolution::from_matches(matches),
                     }
                 }
                 ("bundle", Some(matches)) => {
                     Cmd::Bundle {
                         variant: Bundle::from_matches(matches),
+                        fallback_to_template: matches.is_present("fallback-to-template"),
                         package: SelectedPackage::from_matches(matches),
+                        resolution: Resolution::from_matches(matches),
                     }
                 }
                 ("thirdparty", Some(matches)) => {
@@ -324,3 +559,14 @@ impl FromStr for By {
         }
     }
 }
+
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            s => Err(format!("Cannot parse Format from '{}'", s)),
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use glob::{MatchOptions, Pattern};
+use cargo::CargoResult;
+
+use license::License;
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: false,
+    require_literal_separator: false,
+    require_literal_leading_dot: false,
+};
+
+/// The `[policy]` section of a `lichking.toml`, lists of SPDX identifiers or
+/// globs matching them
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Lists {
+    allow: Vec<String>,
+    warn: Vec<String>,
+    deny: Vec<String>,
+}
+
+/// How a license is treated by the policy, in order of increasing severity
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Decision {
+    Allow,
+    Warn,
+    Review,
+    Deny,
+}
+
+/// An entry in one of the lists, a glob matching a license optionally
+/// followed by `WITH` and a glob matching an exception, e.g.
+/// `GPL-* WITH Classpath-exception-*`
+struct Entry {
+    license: Pattern,
+    exception: Option<Pattern>,
+}
+
+impl Entry {
+    fn new(entry: &str) -> CargoResult<Entry> {
+        let compile = |pattern: &str| Pattern::new(pattern).map_err(|err| format_err!("invalid policy pattern '{}': {}", entry, err));
+        let words = entry.split_whitespace().collect::<Vec<_>>();
+        if words.len() == 1 {
+            Ok(Entry { license: compile(words[0])?, exception: None })
+        } else if words.len() == 3 && words[1].eq_ignore_ascii_case("WITH") {
+            Ok(Entry { license: compile(words[0])?, exception: Some(compile(words[2])?) })
+        } else {
+            bail!("invalid policy pattern '{}': expected a license, optionally followed by WITH and an exception", entry)
+        }
+    }
+
+    /// Entries without an exception only match licenses without one, and
+    /// likewise the other way around
+    fn matches(&self, license: &str, exception: Option<&str>) -> bool {
+        let exception = match (self.exception.as_ref(), exception) {
+            (Some(pattern), Some(exception)) => pattern.matches_with(exception, &MATCH_OPTIONS),
+            (None, None) => true,
+            _ => false,
+        };
+        exception && self.license.matches_with(license, &MATCH_OPTIONS)
+    }
+}
+
+pub struct Policy {
+    allow: Vec<Entry>,
+    warn: Vec<Entry>,
+    deny: Vec<Entry>,
+}
+
+impl Policy {
+    pub fn new(lists: Lists) -> CargoResult<Policy> {
+        fn compile(entries: Vec<String>) -> CargoResult<Vec<Entry>> {
+            entries.iter().map(|entry| Entry::new(entry)).collect()
+        }
+
+        Ok(Policy {
+            allow: compile(lists.allow)?,
+            warn: compile(lists.warn)?,
+            deny: compile(lists.deny)?,
+        })
+    }
+
+    /// Any of the alternatives in an `OR` can be chosen so the most lenient
+    /// applies, while every license in an `AND` must be complied with so the
+    /// strictest applies
+    pub fn evaluate(&self, license: &License) -> Decision {
+        match *license {
+            License::Or(ref licenses) => {
+                licenses.iter().map(|license| self.evaluate(license)).min().unwrap_or(Decision::Review)
+            }
+            License::And(ref licenses) => {
+                licenses.iter().map(|license| self.evaluate(license)).max().unwrap_or(Decision::Review)
+            }
+            License::With(ref base, ref exception) => {
+                // Entries for the specific combination, e.g.
+                // `GPL-2.0-only WITH Classpath-exception-2.0`, take priority
+                // over entries for the base license
+                self.lookup(&base.to_string(), Some(&exception.to_string())).unwrap_or_else(|| self.evaluate(base))
+            }
+            License::Unspecified => {
+                self.lookup("NONE", None).unwrap_or(Decision::Review)
+            }
+            License::File(_) => Decision::Review,
+            ref license => {
+                self.lookup(&license.to_string(), None).unwrap_or(Decision::Review)
+            }
+        }
+    }
+
+    fn lookup(&self, license: &str, exception: Option<&str>) -> Option<Decision> {
+        let matches = |entries: &[Entry]| entries.iter().any(|entry| entry.matches(license, exception));
+        if matches(&self.deny) {
+            Some(Decision::Deny)
+        } else if matches(&self.allow) {
+            Some(Decision::Allow)
+        } else if matches(&self.warn) {
+            Some(Decision::Warn)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use void::ResultVoidExt;
+
+    use license::License;
+
+    use super::{Decision, Lists, Policy};
+
+    fn policy(allow: &[&str], warn: &[&str], deny: &[&str]) -> Policy {
+        let list = |entries: &[&str]| entries.iter().map(|entry| entry.to_string()).collect();
+        Policy::new(Lists { allow: list(allow), warn: list(warn), deny: list(deny) }).unwrap()
+    }
+
+    fn evaluate(policy: &Policy, license: &str) -> Decision {
+        policy.evaluate(&license.parse().void_unwrap())
+    }
+
+    #[test]
+    fn globs() {
+        let policy = policy(&["MIT", "bsd-*"], &["MPL-?.0"], &["GPL-*"]);
+        assert_eq!(evaluate(&policy, "MIT"), Decision::Allow);
+        assert_eq!(evaluate(&policy, "BSD-3-Clause"), Decision::Allow);
+        assert_eq!(evaluate(&policy, "MPL-2.0"), Decision::Warn);
+        assert_eq!(evaluate(&policy, "GPL-3.0-or-later"), Decision::Deny);
+        assert_eq!(evaluate(&policy, "ISC"), Decision::Review);
+    }
+
+    #[test]
+    fn deny_wins_over_allow() {
+        let policy = policy(&["*"], &["GPL-2.0-only"], &["GPL-*"]);
+        assert_eq!(evaluate(&policy, "MIT"), Decision::Allow);
+        assert_eq!(evaluate(&policy, "GPL-2.0-only"), Decision::Deny);
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(policy(&["MIT"], &[], &[]).evaluate(&License::Unspecified), Decision::Review);
+        assert_eq!(policy(&["NONE"], &[], &[]).evaluate(&License::Unspecified), Decision::Allow);
+        assert_eq!(policy(&[], &[], &["NONE"]).evaluate(&License::Unspecified), Decision::Deny);
+        assert_eq!(policy(&["*"], &[], &[]).evaluate(&License::File("LICENSE".into())), Decision::Review);
+    }
+
+    #[test]
+    fn or_is_lenient_and_is_strict() {
+        let policy = policy(&["MIT", "Apache-2.0"], &["ISC"], &["GPL-*"]);
+        assert_eq!(evaluate(&policy, "MIT OR GPL-3.0-only"), Decision::Allow);
+        assert_eq!(evaluate(&policy, "ISC OR GPL-3.0-only"), Decision::Warn);
+        assert_eq!(evaluate(&policy, "MIT AND GPL-3.0-only"), Decision::Deny);
+        assert_eq!(evaluate(&policy, "MIT AND ISC"), Decision::Warn);
+        assert_eq!(evaluate(&policy, "MIT AND Zlib"), Decision::Review);
+        assert_eq!(evaluate(&policy, "(MIT OR GPL-3.0-only) AND Apache-2.0"), Decision::Allow);
+    }
+
+    #[test]
+    fn exceptions() {
+        let policy = policy(&["MIT", "GPL-2.0-only WITH Classpath-exception-2.0", "Apache-* with llvm-*"], &[], &["GPL-*"]);
+        assert_eq!(evaluate(&policy, "GPL-2.0-only WITH Classpath-exception-2.0"), Decision::Allow);
+        assert_eq!(evaluate(&policy, "GPL-2.0-with-classpath-exception"), Decision::Allow);
+        assert_eq!(evaluate(&policy, "Apache-2.0 WITH LLVM-exception"), Decision::Allow);
+        // Otherwise the base license is looked up on its own
+        assert_eq!(evaluate(&policy, "GPL-2.0-only WITH GCC-exception-2.0"), Decision::Deny);
+        assert_eq!(evaluate(&policy, "MIT WITH LLVM-exception"), Decision::Allow);
+        assert_eq!(evaluate(&policy, "GPL-2.0-only"), Decision::Deny);
+        assert_eq!(evaluate(&policy, "Apache-2.0"), Decision::Review);
+    }
+
+    #[test]
+    fn invalid_entries() {
+        for entry in &["MIT OR Apache-2.0", "GPL-2.0-only WITH", "GPL-2.0-only AND Classpath-exception-2.0", "[MIT"] {
+            let lists = Lists { allow: vec![entry.to_string()], warn: Vec::new(), deny: Vec::new() };
+            assert!(Policy::new(lists).is_err(), "{}", entry);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::fs::File;
+use std::io::Read;
+
+use cargo::core::Workspace;
+use cargo::core::dependency::Kind;
+use cargo::CargoResult;
+use toml;
+
+use clarification::{self, Clarification};
+use compatibility::{self, Matrix};
+use discovery::{self, Discovery};
+use policy::{self, Policy};
+
+pub const FILE_NAME: &str = "lichking.toml";
+
+/// How to treat dependencies that are only used to build or test the roots,
+/// and so don't end up linked into them, ordered from least to most strict
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Treatment {
+    /// Leave them out of checks and bundles entirely
+    Ignore,
+    /// Report problems with them as warnings only
+    Warn,
+    /// Check them the same as linked dependencies
+    Check,
+}
+
+
+/// The contents of a `lichking.toml` file
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+struct Manifest {
+    compatibility: Vec<compatibility::Rule>,
+    policy: Option<policy::Lists>,
+    clarify: Vec<clarification::Entry>,
+    build_dependencies: Treatment,
+    dev_dependencies: Treatment,
+    discovery: discovery::Options,
+}
+
+impl Default for Manifest {
+    fn default() -> Manifest {
+        Manifest {
+            compatibility: Vec::new(),
+            policy: None,
+            clarify: Vec::new(),
+            // Build dependencies can generate code or build vendored
+            // libraries that get linked into the roots, so are checked the
+            // same as normal dependencies
+            build_dependencies: Treatment::Check,
+            dev_dependencies: Treatment::Warn,
+            discovery: discovery::Options::default(),
+        }
+    }
+}
+
+/// Project specific settings, read from a `lichking.toml` in the root of the
+/// workspace if there is one
+pub struct Settings {
+    pub compatibility: Matrix,
+    pub policy: Option<Policy>,
+    pub clarifications: Vec<Clarification>,
+    pub build_dependencies: Treatment,
+    pub dev_dependencies: Treatment,
+    pub discovery: Discovery,
+}
+
+impl Settings {
+    /// How to treat a package used by the roots in the given ways, the
+    /// strictest of the treatments for each of them
+    pub fn treatment(&self, kinds: &[Kind]) -> Treatment {
+        kinds.iter()
+            .map(|kind| match *kind {
+                Kind::Normal => Treatment::Check,
+                Kind::Build => self.build_dependencies,
+                Kind::Development => self.dev_dependencies,
+            })
+            .max()
+            .unwrap_or(Treatment::Check)
+    }
+}
+
+pub fn load(workspace: &Workspace) -> CargoResult<Settings> {
+    let path = workspace.root().join(FILE_NAME);
+
+    let manifest: Manifest = if path.exists() {
+        let mut s = String::new();
+        File::open(&path)?.read_to_string(&mut s)?;
+        toml::from_str(&s).map_err(|err| format_err!("failed to parse {}: {}", path.display(), err))?
+    } else {
+        Manifest::default()
+    };
+
+    let mut compatibility = Matrix::bundled()?;
+    compatibility.extend(manifest.compatibility);
+
+    let policy = match manifest.policy {
+        Some(lists) => Some(Policy::new(lists)?),
+        None => None,
+    };
+
+    let clarifications = manifest.clarify
+        .into_iter()
+        .map(Clarification::new)
+        .collect::<CargoResult<_>>()?;
+
+    Ok(Settings {
+        compatibility,
+        policy,
+        clarifications,
+        build_dependencies: manifest.build_dependencies,
+        dev_dependencies: manifest.dev_dependencies,
+        discovery: Discovery::new(manifest.discovery)?,
+    })
+}
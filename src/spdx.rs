@@ -0,0 +1,822 @@
+//! The [SPDX License List](https://spdx.org/licenses/) version 3.27.0
+//!
+//! This file was generated by `scripts/generate-spdx.py`, do not edit it by hand
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
+pub enum Category {
+    PublicDomain,
+    Permissive,
+    WeakCopyleft,
+    StrongCopyleft,
+    NetworkCopyleft,
+    Other,
+}
+
+#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
+pub struct LicenseId {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub deprecated: bool,
+    pub osi_approved: bool,
+    pub fsf_libre: bool,
+    pub category: Category,
+}
+
+#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
+pub struct ExceptionId {
+    pub id: &'static str,
+    pub deprecated: bool,
+}
+
+pub const VERSION: &str = "3.27.0";
+
+pub const LICENSES: &[LicenseId] = &[
+    LicenseId { id: "0BSD", name: "BSD Zero Clause License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::PublicDomain },
+    LicenseId { id: "3D-Slicer-1.0", name: "3D Slicer License v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "AAL", name: "Attribution Assurance License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Abstyles", name: "Abstyles License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "AdaCore-doc", name: "AdaCore Doc License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Adobe-2006", name: "Adobe Systems Incorporated Source Code License Agreement", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Adobe-Display-PostScript", name: "Adobe Display PostScript License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Adobe-Glyph", name: "Adobe Glyph List License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Adobe-Utopia", name: "Adobe Utopia Font License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ADSL", name: "Amazon Digital Services License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "AFL-1.1", name: "Academic Free License v1.1", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "AFL-1.2", name: "Academic Free License v1.2", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "AFL-2.0", name: "Academic Free License v2.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "AFL-2.1", name: "Academic Free License v2.1", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "AFL-3.0", name: "Academic Free License v3.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "Afmparse", name: "Afmparse License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "AGPL-1.0", name: "Affero General Public License v1.0", deprecated: true, osi_approved: false, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "AGPL-1.0-only", name: "Affero General Public License v1.0 only", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::NetworkCopyleft },
+    LicenseId { id: "AGPL-1.0-or-later", name: "Affero General Public License v1.0 or later", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::NetworkCopyleft },
+    LicenseId { id: "AGPL-3.0", name: "GNU Affero General Public License v3.0", deprecated: true, osi_approved: true, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "AGPL-3.0-only", name: "GNU Affero General Public License v3.0 only", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "AGPL-3.0-or-later", name: "GNU Affero General Public License v3.0 or later", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "Aladdin", name: "Aladdin Free Public License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "AMD-newlib", name: "AMD newlib License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "AMDPLPA", name: "AMD's plpa_map.c License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "AML", name: "Apple MIT License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "AML-glslang", name: "AML glslang variant License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "AMPAS", name: "Academy of Motion Picture Arts and Sciences BSD", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ANTLR-PD", name: "ANTLR Software Rights Notice", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ANTLR-PD-fallback", name: "ANTLR Software Rights Notice with license fallback", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "any-OSI", name: "Any OSI License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "any-OSI-perl-modules", name: "Any OSI License - Perl Modules", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Apache-1.0", name: "Apache License 1.0", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "Apache-1.1", name: "Apache License 1.1", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "Apache-2.0", name: "Apache License 2.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "APAFML", name: "Adobe Postscript AFM License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "APL-1.0", name: "Adaptive Public License 1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "App-s2p", name: "App::s2p License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "APSL-1.0", name: "Apple Public Source License 1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "APSL-1.1", name: "Apple Public Source License 1.1", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "APSL-1.2", name: "Apple Public Source License 1.2", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "APSL-2.0", name: "Apple Public Source License 2.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "Arphic-1999", name: "Arphic Public License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Artistic-1.0", name: "Artistic License 1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Artistic-1.0-cl8", name: "Artistic License 1.0 w/clause 8", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Artistic-1.0-Perl", name: "Artistic License 1.0 (Perl)", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Artistic-2.0", name: "Artistic License 2.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "Artistic-dist", name: "Artistic License 1.0 (dist)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Aspell-RU", name: "Aspell Russian License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ASWF-Digital-Assets-1.0", name: "ASWF Digital Assets License version 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ASWF-Digital-Assets-1.1", name: "ASWF Digital Assets License 1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Baekmuk", name: "Baekmuk License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Bahyph", name: "Bahyph License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Barr", name: "Barr License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "bcrypt-Solar-Designer", name: "bcrypt Solar Designer License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Beerware", name: "Beerware License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Bitstream-Charter", name: "Bitstream Charter Font License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Bitstream-Vera", name: "Bitstream Vera Font License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "BitTorrent-1.0", name: "BitTorrent Open Source License v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "BitTorrent-1.1", name: "BitTorrent Open Source License v1.1", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "blessing", name: "SQLite Blessing", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::PublicDomain },
+    LicenseId { id: "BlueOak-1.0.0", name: "Blue Oak Model License 1.0.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Boehm-GC", name: "Boehm-Demers-Weiser GC License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Boehm-GC-without-fee", name: "Boehm-Demers-Weiser GC License (without fee)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Borceux", name: "Borceux license", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Brian-Gladman-2-Clause", name: "Brian Gladman 2-Clause License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Brian-Gladman-3-Clause", name: "Brian Gladman 3-Clause License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "BSD-1-Clause", name: "BSD 1-Clause License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-2-Clause", name: "BSD 2-Clause \"Simplified\" License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "BSD-2-Clause-Darwin", name: "BSD 2-Clause - Ian Darwin variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-2-Clause-first-lines", name: "BSD 2-Clause - first lines requirement", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-2-Clause-FreeBSD", name: "BSD 2-Clause FreeBSD License", deprecated: true, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "BSD-2-Clause-NetBSD", name: "BSD 2-Clause NetBSD License", deprecated: true, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "BSD-2-Clause-Patent", name: "BSD-2-Clause Plus Patent License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-2-Clause-pkgconf-disclaimer", name: "BSD 2-Clause pkgconf disclaimer variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-2-Clause-Views", name: "BSD 2-Clause with views sentence", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause", name: "BSD 3-Clause \"New\" or \"Revised\" License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-acpica", name: "BSD 3-Clause acpica variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-Attribution", name: "BSD with attribution", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-Clear", name: "BSD 3-Clause Clear License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-flex", name: "BSD 3-Clause Flex variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-HP", name: "Hewlett-Packard BSD variant license", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-LBNL", name: "Lawrence Berkeley National Labs BSD variant license", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-Modification", name: "BSD 3-Clause Modification", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-No-Military-License", name: "BSD 3-Clause No Military License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-No-Nuclear-License", name: "BSD 3-Clause No Nuclear License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-No-Nuclear-License-2014", name: "BSD 3-Clause No Nuclear License 2014", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-No-Nuclear-Warranty", name: "BSD 3-Clause No Nuclear Warranty", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-Open-MPI", name: "BSD 3-Clause Open MPI variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-3-Clause-Sun", name: "BSD 3-Clause Sun Microsystems", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-4-Clause", name: "BSD 4-Clause \"Original\" or \"Old\" License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "BSD-4-Clause-Shortened", name: "BSD 4 Clause Shortened", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-4-Clause-UC", name: "BSD-4-Clause (University of California-Specific)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-4.3RENO", name: "BSD 4.3 RENO License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-4.3TAHOE", name: "BSD 4.3 TAHOE License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-Advertising-Acknowledgement", name: "BSD Advertising Acknowledgement License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-Attribution-HPND-disclaimer", name: "BSD with Attribution and HPND disclaimer", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-Inferno-Nettverk", name: "BSD-Inferno-Nettverk", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-Protection", name: "BSD Protection License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "BSD-Source-beginning-file", name: "BSD Source Code Attribution - beginning of file variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-Source-Code", name: "BSD Source Code Attribution", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-Systemics", name: "Systemics BSD variant license", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSD-Systemics-W3Works", name: "Systemics W3Works BSD variant license", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "BSL-1.0", name: "Boost Software License 1.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "BUSL-1.1", name: "Business Source License 1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "bzip2-1.0.5", name: "bzip2 and libbzip2 License v1.0.5", deprecated: true, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "bzip2-1.0.6", name: "bzip2 and libbzip2 License v1.0.6", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "C-UDA-1.0", name: "Computational Use of Data Agreement v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CAL-1.0", name: "Cryptographic Autonomy License 1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::NetworkCopyleft },
+    LicenseId { id: "CAL-1.0-Combined-Work-Exception", name: "Cryptographic Autonomy License 1.0 (Combined Work Exception)", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::NetworkCopyleft },
+    LicenseId { id: "Caldera", name: "Caldera License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Caldera-no-preamble", name: "Caldera License (without preamble)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Catharon", name: "Catharon License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CATOSL-1.1", name: "Computer Associates Trusted Open Source License 1.1", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-1.0", name: "Creative Commons Attribution 1.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CC-BY-2.0", name: "Creative Commons Attribution 2.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CC-BY-2.5", name: "Creative Commons Attribution 2.5 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CC-BY-2.5-AU", name: "Creative Commons Attribution 2.5 Australia", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CC-BY-3.0", name: "Creative Commons Attribution 3.0 Unported", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CC-BY-3.0-AT", name: "Creative Commons Attribution 3.0 Austria", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CC-BY-3.0-AU", name: "Creative Commons Attribution 3.0 Australia", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CC-BY-3.0-DE", name: "Creative Commons Attribution 3.0 Germany", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CC-BY-3.0-IGO", name: "Creative Commons Attribution 3.0 IGO", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CC-BY-3.0-NL", name: "Creative Commons Attribution 3.0 Netherlands", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CC-BY-3.0-US", name: "Creative Commons Attribution 3.0 United States", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CC-BY-4.0", name: "Creative Commons Attribution 4.0 International", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "CC-BY-NC-1.0", name: "Creative Commons Attribution Non Commercial 1.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-2.0", name: "Creative Commons Attribution Non Commercial 2.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-2.5", name: "Creative Commons Attribution Non Commercial 2.5 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-3.0", name: "Creative Commons Attribution Non Commercial 3.0 Unported", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-3.0-DE", name: "Creative Commons Attribution Non Commercial 3.0 Germany", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-4.0", name: "Creative Commons Attribution Non Commercial 4.0 International", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-ND-1.0", name: "Creative Commons Attribution Non Commercial No Derivatives 1.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-ND-2.0", name: "Creative Commons Attribution Non Commercial No Derivatives 2.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-ND-2.5", name: "Creative Commons Attribution Non Commercial No Derivatives 2.5 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-ND-3.0", name: "Creative Commons Attribution Non Commercial No Derivatives 3.0 Unported", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-ND-3.0-DE", name: "Creative Commons Attribution Non Commercial No Derivatives 3.0 Germany", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-ND-3.0-IGO", name: "Creative Commons Attribution Non Commercial No Derivatives 3.0 IGO", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-ND-4.0", name: "Creative Commons Attribution Non Commercial No Derivatives 4.0 International", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-SA-1.0", name: "Creative Commons Attribution Non Commercial Share Alike 1.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-SA-2.0", name: "Creative Commons Attribution Non Commercial Share Alike 2.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-SA-2.0-DE", name: "Creative Commons Attribution Non Commercial Share Alike 2.0 Germany", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-SA-2.0-FR", name: "Creative Commons Attribution-NonCommercial-ShareAlike 2.0 France", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-SA-2.0-UK", name: "Creative Commons Attribution Non Commercial Share Alike 2.0 England and Wales", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-SA-2.5", name: "Creative Commons Attribution Non Commercial Share Alike 2.5 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-SA-3.0", name: "Creative Commons Attribution Non Commercial Share Alike 3.0 Unported", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-SA-3.0-DE", name: "Creative Commons Attribution Non Commercial Share Alike 3.0 Germany", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-SA-3.0-IGO", name: "Creative Commons Attribution Non Commercial Share Alike 3.0 IGO", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-NC-SA-4.0", name: "Creative Commons Attribution Non Commercial Share Alike 4.0 International", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-ND-1.0", name: "Creative Commons Attribution No Derivatives 1.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-ND-2.0", name: "Creative Commons Attribution No Derivatives 2.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-ND-2.5", name: "Creative Commons Attribution No Derivatives 2.5 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-ND-3.0", name: "Creative Commons Attribution No Derivatives 3.0 Unported", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-ND-3.0-DE", name: "Creative Commons Attribution No Derivatives 3.0 Germany", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-ND-4.0", name: "Creative Commons Attribution No Derivatives 4.0 International", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC-BY-SA-1.0", name: "Creative Commons Attribution Share Alike 1.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CC-BY-SA-2.0", name: "Creative Commons Attribution Share Alike 2.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CC-BY-SA-2.0-UK", name: "Creative Commons Attribution Share Alike 2.0 England and Wales", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CC-BY-SA-2.1-JP", name: "Creative Commons Attribution Share Alike 2.1 Japan", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CC-BY-SA-2.5", name: "Creative Commons Attribution Share Alike 2.5 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CC-BY-SA-3.0", name: "Creative Commons Attribution Share Alike 3.0 Unported", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CC-BY-SA-3.0-AT", name: "Creative Commons Attribution Share Alike 3.0 Austria", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CC-BY-SA-3.0-DE", name: "Creative Commons Attribution Share Alike 3.0 Germany", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CC-BY-SA-3.0-IGO", name: "Creative Commons Attribution-ShareAlike 3.0 IGO", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CC-BY-SA-4.0", name: "Creative Commons Attribution Share Alike 4.0 International", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "CC-PDDC", name: "Creative Commons Public Domain Dedication and Certification", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::PublicDomain },
+    LicenseId { id: "CC-PDM-1.0", name: "Creative    Commons Public Domain Mark 1.0 Universal", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::PublicDomain },
+    LicenseId { id: "CC-SA-1.0", name: "Creative Commons Share Alike 1.0 Generic", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CC0-1.0", name: "Creative Commons Zero v1.0 Universal", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::PublicDomain },
+    LicenseId { id: "CDDL-1.0", name: "Common Development and Distribution License 1.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "CDDL-1.1", name: "Common Development and Distribution License 1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "CDL-1.0", name: "Common Documentation License 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CDLA-Permissive-1.0", name: "Community Data License Agreement Permissive 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CDLA-Permissive-2.0", name: "Community Data License Agreement Permissive 2.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "CDLA-Sharing-1.0", name: "Community Data License Agreement Sharing 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CECILL-1.0", name: "CeCILL Free Software License Agreement v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CECILL-1.1", name: "CeCILL Free Software License Agreement v1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CECILL-2.0", name: "CeCILL Free Software License Agreement v2.0", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "CECILL-2.1", name: "CeCILL Free Software License Agreement v2.1", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "CECILL-B", name: "CeCILL-B Free Software License Agreement", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "CECILL-C", name: "CeCILL-C Free Software License Agreement", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "CERN-OHL-1.1", name: "CERN Open Hardware Licence v1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CERN-OHL-1.2", name: "CERN Open Hardware Licence v1.2", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CERN-OHL-P-2.0", name: "CERN Open Hardware Licence Version 2 - Permissive", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CERN-OHL-S-2.0", name: "CERN Open Hardware Licence Version 2 - Strongly Reciprocal", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CERN-OHL-W-2.0", name: "CERN Open Hardware Licence Version 2 - Weakly Reciprocal", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CFITSIO", name: "CFITSIO License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "check-cvs", name: "check-cvs License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "checkmk", name: "Checkmk License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ClArtistic", name: "Clarified Artistic License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "Clips", name: "Clips License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CMU-Mach", name: "CMU Mach License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CMU-Mach-nodoc", name: "CMU    Mach - no notices-in-documentation variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CNRI-Jython", name: "CNRI Jython License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CNRI-Python", name: "CNRI Python License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CNRI-Python-GPL-Compatible", name: "CNRI Python Open Source GPL Compatible License Agreement", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "COIL-1.0", name: "Copyfree Open Innovation License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Community-Spec-1.0", name: "Community Specification License 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Condor-1.1", name: "Condor Public License v1.1", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "copyleft-next-0.3.0", name: "copyleft-next 0.3.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "copyleft-next-0.3.1", name: "copyleft-next 0.3.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Cornell-Lossless-JPEG", name: "Cornell Lossless JPEG License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CPAL-1.0", name: "Common Public Attribution License 1.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "CPL-1.0", name: "Common Public License 1.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "CPOL-1.02", name: "Code Project Open License 1.02", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Cronyx", name: "Cronyx License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Crossword", name: "Crossword License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CryptoSwift", name: "CryptoSwift License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CrystalStacker", name: "CrystalStacker License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "CUA-OPL-1.0", name: "CUA Office Public License v1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Cube", name: "Cube License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "curl", name: "curl License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "cve-tou", name: "Common Vulnerability Enumeration ToU License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "D-FSL-1.0", name: "Deutsche Freie Software Lizenz", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "DEC-3-Clause", name: "DEC 3-Clause License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "diffmark", name: "diffmark license", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "DL-DE-BY-2.0", name: "Data licence Germany – attribution – version 2.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "DL-DE-ZERO-2.0", name: "Data licence Germany – zero – version 2.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "DOC", name: "DOC License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "DocBook-DTD", name: "DocBook DTD License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "DocBook-Schema", name: "DocBook Schema License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "DocBook-Stylesheet", name: "DocBook Stylesheet License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "DocBook-XML", name: "DocBook XML License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Dotseqn", name: "Dotseqn License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "DRL-1.0", name: "Detection Rule License 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "DRL-1.1", name: "Detection Rule License 1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "DSDP", name: "DSDP License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "dtoa", name: "David M. Gay dtoa License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "dvipdfm", name: "dvipdfm License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ECL-1.0", name: "Educational Community License v1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "ECL-2.0", name: "Educational Community License v2.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "eCos-2.0", name: "eCos license version 2.0", deprecated: true, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "EFL-1.0", name: "Eiffel Forum License v1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "EFL-2.0", name: "Eiffel Forum License v2.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "eGenix", name: "eGenix.com Public License 1.1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Elastic-2.0", name: "Elastic License 2.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Entessa", name: "Entessa Public License v1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "EPICS", name: "EPICS Open License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "EPL-1.0", name: "Eclipse Public License 1.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "EPL-2.0", name: "Eclipse Public License 2.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "ErlPL-1.1", name: "Erlang Public License v1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "etalab-2.0", name: "Etalab Open License 2.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "EUDatagrid", name: "EU DataGrid Software License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "EUPL-1.0", name: "European Union Public License 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::NetworkCopyleft },
+    LicenseId { id: "EUPL-1.1", name: "European Union Public License 1.1", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "EUPL-1.2", name: "European Union Public License 1.2", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "Eurosym", name: "Eurosym License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Fair", name: "Fair License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "FBM", name: "Fuzzy Bitmap License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "FDK-AAC", name: "Fraunhofer FDK AAC Codec Library", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Ferguson-Twofish", name: "Ferguson Twofish License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Frameworx-1.0", name: "Frameworx Open License 1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "FreeBSD-DOC", name: "FreeBSD Documentation License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "FreeImage", name: "FreeImage Public License v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "FSFAP", name: "FSF All Permissive License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "FSFAP-no-warranty-disclaimer", name: "FSF All Permissive License (without Warranty)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "FSFUL", name: "FSF Unlimited License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "FSFULLR", name: "FSF Unlimited License (with License Retention)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "FSFULLRSD", name: "FSF Unlimited License (with License Retention and Short Disclaimer)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "FSFULLRWD", name: "FSF Unlimited License (With License Retention and Warranty Disclaimer)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "FSL-1.1-ALv2", name: "Functional Source License, Version 1.1, ALv2 Future License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "FSL-1.1-MIT", name: "Functional Source License, Version 1.1, MIT Future License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "FTL", name: "Freetype Project License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "Furuseth", name: "Furuseth License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "fwlw", name: "fwlw License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Game-Programming-Gems", name: "Game Programming Gems License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "GCR-docs", name: "Gnome GCR Documentation License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "GD", name: "GD License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "generic-xts", name: "Generic XTS License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "GFDL-1.1", name: "GNU Free Documentation License v1.1", deprecated: true, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.1-invariants", name: "GNU Free Documentation License v1.1 only - invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.1-invariants-only", name: "GNU Free Documentation License v1.1 only - invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.1-invariants-or-later", name: "GNU Free Documentation License v1.1 or later - invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.1-no-invariants", name: "GNU Free Documentation License v1.1 only - no invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.1-no-invariants-only", name: "GNU Free Documentation License v1.1 only - no invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.1-no-invariants-or-later", name: "GNU Free Documentation License v1.1 or later - no invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.1-only", name: "GNU Free Documentation License v1.1 only", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.1-or-later", name: "GNU Free Documentation License v1.1 or later", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.2", name: "GNU Free Documentation License v1.2", deprecated: true, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.2-invariants", name: "GNU Free Documentation License v1.2 only - invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.2-invariants-only", name: "GNU Free Documentation License v1.2 only - invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.2-invariants-or-later", name: "GNU Free Documentation License v1.2 or later - invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.2-no-invariants", name: "GNU Free Documentation License v1.2 only - no invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.2-no-invariants-only", name: "GNU Free Documentation License v1.2 only - no invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.2-no-invariants-or-later", name: "GNU Free Documentation License v1.2 or later - no invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.2-only", name: "GNU Free Documentation License v1.2 only", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.2-or-later", name: "GNU Free Documentation License v1.2 or later", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.3", name: "GNU Free Documentation License v1.3", deprecated: true, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.3-invariants", name: "GNU Free Documentation License v1.3 only - invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.3-invariants-only", name: "GNU Free Documentation License v1.3 only - invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.3-invariants-or-later", name: "GNU Free Documentation License v1.3 or later - invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.3-no-invariants", name: "GNU Free Documentation License v1.3 only - no invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.3-no-invariants-only", name: "GNU Free Documentation License v1.3 only - no invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.3-no-invariants-or-later", name: "GNU Free Documentation License v1.3 or later - no invariants", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.3-only", name: "GNU Free Documentation License v1.3 only", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GFDL-1.3-or-later", name: "GNU Free Documentation License v1.3 or later", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "Giftware", name: "Giftware License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "GL2PS", name: "GL2PS License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Glide", name: "3dfx Glide License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Glulxe", name: "Glulxe License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "GLWTPL", name: "Good Luck With That Public License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "gnuplot", name: "gnuplot License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "GPL-1.0", name: "GNU General Public License v1.0 only", deprecated: true, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-1.0+", name: "GNU General Public License v1.0 or later", deprecated: true, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-1.0-only", name: "GNU General Public License v1.0 only", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-1.0-or-later", name: "GNU General Public License v1.0 or later", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-2.0", name: "GNU General Public License v2.0 only", deprecated: true, osi_approved: true, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-2.0+", name: "GNU General Public License v2.0 or later", deprecated: true, osi_approved: true, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-2.0-only", name: "GNU General Public License v2.0 only", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-2.0-or-later", name: "GNU General Public License v2.0 or later", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-2.0-with-autoconf-exception", name: "GNU General Public License v2.0 w/Autoconf exception", deprecated: true, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "GPL-2.0-with-bison-exception", name: "GNU General Public License v2.0 w/Bison exception", deprecated: true, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "GPL-2.0-with-classpath-exception", name: "GNU General Public License v2.0 w/Classpath exception", deprecated: true, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "GPL-2.0-with-font-exception", name: "GNU General Public License v2.0 w/Font exception", deprecated: true, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "GPL-2.0-with-GCC-exception", name: "GNU General Public License v2.0 w/GCC Runtime Library exception", deprecated: true, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "GPL-3.0", name: "GNU General Public License v3.0 only", deprecated: true, osi_approved: true, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-3.0+", name: "GNU General Public License v3.0 or later", deprecated: true, osi_approved: true, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-3.0-only", name: "GNU General Public License v3.0 only", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-3.0-or-later", name: "GNU General Public License v3.0 or later", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "GPL-3.0-with-autoconf-exception", name: "GNU General Public License v3.0 w/Autoconf exception", deprecated: true, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "GPL-3.0-with-GCC-exception", name: "GNU General Public License v3.0 w/GCC Runtime Library exception", deprecated: true, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "Graphics-Gems", name: "Graphics Gems License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "gSOAP-1.3b", name: "gSOAP Public License v1.3b", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "gtkbook", name: "gtkbook License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Gutmann", name: "Gutmann License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "HaskellReport", name: "Haskell Language Report License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "HDF5", name: "HDF5 License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "hdparm", name: "hdparm License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "HIDAPI", name: "HIDAPI License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Hippocratic-2.1", name: "Hippocratic License 2.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "HP-1986", name: "Hewlett-Packard 1986 License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "HP-1989", name: "Hewlett-Packard 1989 License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "HPND", name: "Historical Permission Notice and Disclaimer", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "HPND-DEC", name: "Historical Permission Notice and Disclaimer - DEC variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-doc", name: "Historical Permission Notice and Disclaimer - documentation variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-doc-sell", name: "Historical Permission Notice and Disclaimer - documentation sell variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-export-US", name: "HPND with US Government export control warning", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-export-US-acknowledgement", name: "HPND with US Government export control warning and acknowledgment", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-export-US-modify", name: "HPND with US Government export control warning and modification rqmt", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-export2-US", name: "HPND with US Government export control and 2 disclaimers", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-Fenneberg-Livingston", name: "Historical Permission Notice and Disclaimer - Fenneberg-Livingston variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-INRIA-IMAG", name: "Historical Permission Notice and Disclaimer    - INRIA-IMAG variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-Intel", name: "Historical Permission Notice and Disclaimer - Intel variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-Kevlin-Henney", name: "Historical Permission Notice and Disclaimer - Kevlin Henney variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-Markus-Kuhn", name: "Historical Permission Notice and Disclaimer - Markus Kuhn variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-merchantability-variant", name: "Historical Permission Notice and Disclaimer - merchantability variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-MIT-disclaimer", name: "Historical Permission Notice and Disclaimer with MIT disclaimer", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-Netrek", name: "Historical Permission Notice and Disclaimer - Netrek variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-Pbmplus", name: "Historical Permission Notice and Disclaimer - Pbmplus variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-sell-MIT-disclaimer-xserver", name: "Historical Permission Notice and Disclaimer - sell xserver variant with MIT disclaimer", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-sell-regexpr", name: "Historical Permission Notice and Disclaimer - sell regexpr variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-sell-variant", name: "Historical Permission Notice and Disclaimer - sell variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-sell-variant-MIT-disclaimer", name: "HPND sell variant with MIT disclaimer", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-sell-variant-MIT-disclaimer-rev", name: "HPND sell variant with MIT disclaimer - reverse", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-UC", name: "Historical Permission Notice and Disclaimer - University of California variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HPND-UC-export-US", name: "Historical Permission Notice and Disclaimer - University of California, US export warning", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "HTMLTIDY", name: "HTML Tidy License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "IBM-pibs", name: "IBM PowerPC Initialization and Boot Software", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ICU", name: "ICU License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "IEC-Code-Components-EULA", name: "IEC    Code Components End-user licence agreement", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "IJG", name: "Independent JPEG Group License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "IJG-short", name: "Independent JPEG Group License - short", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "ImageMagick", name: "ImageMagick License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "iMatix", name: "iMatix Standard Function Library Agreement", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "Imlib2", name: "Imlib2 License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "Info-ZIP", name: "Info-ZIP License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Inner-Net-2.0", name: "Inner Net License v2.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "InnoSetup", name: "Inno Setup License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Intel", name: "Intel Open Source License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "Intel-ACPI", name: "Intel ACPI Software License Agreement", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Interbase-1.0", name: "Interbase Public License v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "IPA", name: "IPA Font License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "IPL-1.0", name: "IBM Public License v1.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "ISC", name: "ISC License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "ISC-Veillard", name: "ISC Veillard variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Jam", name: "Jam License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "JasPer-2.0", name: "JasPer License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "jove", name: "Jove License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "JPL-image", name: "JPL Image Use Policy", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "JPNIC", name: "Japan Network Information Center License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "JSON", name: "JSON License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Kastrup", name: "Kastrup License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Kazlib", name: "Kazlib License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Knuth-CTAN", name: "Knuth CTAN License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LAL-1.2", name: "Licence Art Libre 1.2", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LAL-1.3", name: "Licence Art Libre 1.3", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Latex2e", name: "Latex2e License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Latex2e-translated-notice", name: "Latex2e with translated notice permission", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Leptonica", name: "Leptonica License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LGPL-2.0", name: "GNU Library General Public License v2 only", deprecated: true, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPL-2.0+", name: "GNU Library General Public License v2 or later", deprecated: true, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPL-2.0-only", name: "GNU Library General Public License v2 only", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPL-2.0-or-later", name: "GNU Library General Public License v2 or later", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPL-2.1", name: "GNU Lesser General Public License v2.1 only", deprecated: true, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPL-2.1+", name: "GNU Lesser General Public License v2.1 or later", deprecated: true, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPL-2.1-only", name: "GNU Lesser General Public License v2.1 only", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPL-2.1-or-later", name: "GNU Lesser General Public License v2.1 or later", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPL-3.0", name: "GNU Lesser General Public License v3.0 only", deprecated: true, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPL-3.0+", name: "GNU Lesser General Public License v3.0 or later", deprecated: true, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPL-3.0-only", name: "GNU Lesser General Public License v3.0 only", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPL-3.0-or-later", name: "GNU Lesser General Public License v3.0 or later", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "LGPLLR", name: "Lesser General Public License For Linguistic Resources", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "Libpng", name: "libpng License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "libpng-1.6.35", name: "PNG Reference Library License v1 (for libpng 0.5 through 1.6.35)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "libpng-2.0", name: "PNG Reference Library version 2", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "libselinux-1.0", name: "libselinux public domain notice", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::PublicDomain },
+    LicenseId { id: "libtiff", name: "libtiff License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "libutil-David-Nugent", name: "libutil David Nugent License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LiLiQ-P-1.1", name: "Licence Libre du Québec – Permissive version 1.1", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LiLiQ-R-1.1", name: "Licence Libre du Québec – Réciprocité version 1.1", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LiLiQ-Rplus-1.1", name: "Licence Libre du Québec – Réciprocité forte version 1.1", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Linux-man-pages-1-para", name: "Linux man-pages - 1 paragraph", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Linux-man-pages-copyleft", name: "Linux man-pages Copyleft", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Linux-man-pages-copyleft-2-para", name: "Linux man-pages Copyleft - 2 paragraphs", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Linux-man-pages-copyleft-var", name: "Linux man-pages Copyleft Variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Linux-OpenIB", name: "Linux Kernel Variant of OpenIB.org license", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LOOP", name: "Common Lisp LOOP License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LPD-document", name: "LPD Documentation License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LPL-1.0", name: "Lucent Public License Version 1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LPL-1.02", name: "Lucent Public License v1.02", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "LPPL-1.0", name: "LaTeX Project Public License v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LPPL-1.1", name: "LaTeX Project Public License v1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LPPL-1.2", name: "LaTeX Project Public License v1.2", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "LPPL-1.3a", name: "LaTeX Project Public License v1.3a", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "LPPL-1.3c", name: "LaTeX Project Public License v1.3c", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "lsof", name: "lsof License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Lucida-Bitmap-Fonts", name: "Lucida Bitmap Fonts License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LZMA-SDK-9.11-to-9.20", name: "LZMA SDK License (versions 9.11 to 9.20)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "LZMA-SDK-9.22", name: "LZMA SDK License (versions 9.22 and beyond)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Mackerras-3-Clause", name: "Mackerras 3-Clause License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Mackerras-3-Clause-acknowledgment", name: "Mackerras 3-Clause - acknowledgment variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "magaz", name: "magaz License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "mailprio", name: "mailprio License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "MakeIndex", name: "MakeIndex License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "man2html", name: "man2html License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Martin-Birgmeier", name: "Martin Birgmeier License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "McPhee-slideshow", name: "McPhee Slideshow License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "metamail", name: "metamail License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Minpack", name: "Minpack License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "MIPS", name: "MIPS License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "MirOS", name: "The MirOS Licence", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MIT", name: "MIT License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "MIT-0", name: "MIT No Attribution", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::PublicDomain },
+    LicenseId { id: "MIT-advertising", name: "Enlightenment License (e16)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MIT-Click", name: "MIT Click License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MIT-CMU", name: "CMU License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MIT-enna", name: "enna License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MIT-feh", name: "feh License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MIT-Festival", name: "MIT Festival Variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MIT-Khronos-old", name: "MIT Khronos - old variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MIT-Modern-Variant", name: "MIT License Modern Variant", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MIT-open-group", name: "MIT Open Group variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MIT-testregex", name: "MIT testregex Variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MIT-Wu", name: "MIT Tom Wu Variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MITNFA", name: "MIT +no-false-attribs license", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MMIXware", name: "MMIXware License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Motosoto", name: "Motosoto License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "MPEG-SSG", name: "MPEG Software Simulation", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "mpi-permissive", name: "mpi Permissive License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "mpich2", name: "mpich2 License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "MPL-1.0", name: "Mozilla Public License 1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "MPL-1.1", name: "Mozilla Public License 1.1", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "MPL-2.0", name: "Mozilla Public License 2.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "MPL-2.0-no-copyleft-exception", name: "Mozilla Public License 2.0 (no copyleft exception)", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "mplus", name: "mplus Font License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "MS-LPL", name: "Microsoft Limited Public License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "MS-PL", name: "Microsoft Public License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "MS-RL", name: "Microsoft Reciprocal License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "MTLL", name: "Matrix Template Library License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "MulanPSL-1.0", name: "Mulan Permissive Software License, Version 1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "MulanPSL-2.0", name: "Mulan Permissive Software License, Version 2", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Multics", name: "Multics License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Mup", name: "Mup License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NAIST-2003", name: "Nara Institute of Science and Technology License (2003)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NASA-1.3", name: "NASA Open Source Agreement 1.3", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Naumen", name: "Naumen Public License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NBPL-1.0", name: "Net Boolean Public License v1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NCBI-PD", name: "NCBI Public Domain Notice", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NCGL-UK-2.0", name: "Non-Commercial Government Licence", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NCL", name: "NCL Source Code License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NCSA", name: "University of Illinois/NCSA Open Source License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "Net-SNMP", name: "Net-SNMP License", deprecated: true, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NetCDF", name: "NetCDF license", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Newsletr", name: "Newsletr License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NGPL", name: "Nethack General Public License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ngrep", name: "ngrep License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NICTA-1.0", name: "NICTA Public Software License, Version 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NIST-PD", name: "NIST Public Domain Notice", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NIST-PD-fallback", name: "NIST Public Domain Notice with license fallback", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NIST-Software", name: "NIST Software License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NLOD-1.0", name: "Norwegian Licence for Open Government Data (NLOD) 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NLOD-2.0", name: "Norwegian Licence for Open Government Data (NLOD) 2.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NLPL", name: "No Limit Public License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NOASSERTION", name: "NOASSERTION", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Nokia", name: "Nokia Open Source License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "NOSL", name: "Netizen Open Source License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "Noweb", name: "Noweb License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NPL-1.0", name: "Netscape Public License v1.0", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "NPL-1.1", name: "Netscape Public License v1.1", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "NPOSL-3.0", name: "Non-Profit Open Software License 3.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "NRL", name: "NRL License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NTIA-PD", name: "NTIA Public Domain Notice", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "NTP", name: "NTP License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "NTP-0", name: "NTP No Attribution", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Nunit", name: "Nunit License", deprecated: true, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "O-UDA-1.0", name: "Open Use of Data Agreement v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OAR", name: "OAR License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OCCT-PL", name: "Open CASCADE Technology Public License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OCLC-2.0", name: "OCLC Research Public License 2.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ODbL-1.0", name: "Open Data Commons Open Database License v1.0", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "ODC-By-1.0", name: "Open Data Commons Attribution License v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OFFIS", name: "OFFIS License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OFL-1.0", name: "SIL Open Font License 1.0", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "OFL-1.0-no-RFN", name: "SIL Open Font License 1.0 with no Reserved Font Name", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "OFL-1.0-RFN", name: "SIL Open Font License 1.0 with Reserved Font Name", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "OFL-1.1", name: "SIL Open Font License 1.1", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "OFL-1.1-no-RFN", name: "SIL Open Font License 1.1 with no Reserved Font Name", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "OFL-1.1-RFN", name: "SIL Open Font License 1.1 with Reserved Font Name", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "OGC-1.0", name: "OGC Software License, Version 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OGDL-Taiwan-1.0", name: "Taiwan Open Government Data License, version 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OGL-Canada-2.0", name: "Open Government Licence - Canada", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OGL-UK-1.0", name: "Open Government Licence v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OGL-UK-2.0", name: "Open Government Licence v2.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OGL-UK-3.0", name: "Open Government Licence v3.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OGTSL", name: "Open Group Test Suite License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OLDAP-1.1", name: "Open LDAP Public License v1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-1.2", name: "Open LDAP Public License v1.2", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-1.3", name: "Open LDAP Public License v1.3", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-1.4", name: "Open LDAP Public License v1.4", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.0", name: "Open LDAP Public License v2.0 (or possibly 2.0A and 2.0B)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.0.1", name: "Open LDAP Public License v2.0.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.1", name: "Open LDAP Public License v2.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.2", name: "Open LDAP Public License v2.2", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.2.1", name: "Open LDAP Public License v2.2.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.2.2", name: "Open LDAP Public License 2.2.2", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.3", name: "Open LDAP Public License v2.3", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.4", name: "Open LDAP Public License v2.4", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.5", name: "Open LDAP Public License v2.5", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.6", name: "Open LDAP Public License v2.6", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.7", name: "Open LDAP Public License v2.7", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "OLDAP-2.8", name: "Open LDAP Public License v2.8", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OLFL-1.3", name: "Open Logistics Foundation License Version 1.3", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OML", name: "Open Market License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OpenPBS-2.3", name: "OpenPBS v2.3 Software License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OpenSSL", name: "OpenSSL License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "OpenSSL-standalone", name: "OpenSSL License - standalone", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "OpenVision", name: "OpenVision License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OPL-1.0", name: "Open Public License v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OPL-UK-3.0", name: "United    Kingdom Open Parliament Licence v3.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OPUBL-1.0", name: "Open Publication License v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OSET-PL-2.1", name: "OSET Public License version 2.1", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "OSL-1.0", name: "Open Software License 1.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "OSL-1.1", name: "Open Software License 1.1", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "OSL-2.0", name: "Open Software License 2.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "OSL-2.1", name: "Open Software License 2.1", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "OSL-3.0", name: "Open Software License 3.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "PADL", name: "PADL License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Parity-6.0.0", name: "The Parity Public License 6.0.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Parity-7.0.0", name: "The Parity Public License 7.0.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "PDDL-1.0", name: "Open Data Commons Public Domain Dedication & License 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::PublicDomain },
+    LicenseId { id: "PHP-3.0", name: "PHP License v3.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "PHP-3.01", name: "PHP License v3.01", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "Pixar", name: "Pixar License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "pkgconf", name: "pkgconf License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Plexus", name: "Plexus Classworlds License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "pnmstitch", name: "pnmstitch License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "PolyForm-Noncommercial-1.0.0", name: "PolyForm Noncommercial License 1.0.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "PolyForm-Small-Business-1.0.0", name: "PolyForm Small Business License 1.0.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "PostgreSQL", name: "PostgreSQL License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "PPL", name: "Peer Production License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "PSF-2.0", name: "Python Software Foundation License 2.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "psfrag", name: "psfrag License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "psutils", name: "psutils License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Python-2.0", name: "Python License 2.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "Python-2.0.1", name: "Python License 2.0.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "python-ldap", name: "Python ldap License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Qhull", name: "Qhull License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "QPL-1.0", name: "Q Public License 1.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "QPL-1.0-INRIA-2004", name: "Q Public License 1.0 - INRIA 2004 variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::StrongCopyleft },
+    LicenseId { id: "radvd", name: "radvd License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Rdisc", name: "Rdisc License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "RHeCos-1.1", name: "Red Hat eCos Public License v1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "RPL-1.1", name: "Reciprocal Public License 1.1", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::NetworkCopyleft },
+    LicenseId { id: "RPL-1.5", name: "Reciprocal Public License 1.5", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::NetworkCopyleft },
+    LicenseId { id: "RPSL-1.0", name: "RealNetworks Public Source License v1.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::NetworkCopyleft },
+    LicenseId { id: "RSA-MD", name: "RSA Message-Digest License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "RSCPL", name: "Ricoh Source Code Public License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Ruby", name: "Ruby License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "Ruby-pty", name: "Ruby pty extension license", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "SAX-PD", name: "Sax Public Domain Notice", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::PublicDomain },
+    LicenseId { id: "SAX-PD-2.0", name: "Sax Public Domain Notice 2.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::PublicDomain },
+    LicenseId { id: "Saxpath", name: "Saxpath License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SCEA", name: "SCEA Shared Source License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SchemeReport", name: "Scheme Language Report License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Sendmail", name: "Sendmail License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Sendmail-8.23", name: "Sendmail License 8.23", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Sendmail-Open-Source-1.1", name: "Sendmail Open Source License v1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SGI-B-1.0", name: "SGI Free Software License B v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SGI-B-1.1", name: "SGI Free Software License B v1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SGI-B-2.0", name: "SGI Free Software License B v2.0", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "SGI-OpenGL", name: "SGI OpenGL License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SGP4", name: "SGP4 Permission Notice", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SHL-0.5", name: "Solderpad Hardware License v0.5", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SHL-0.51", name: "Solderpad Hardware License, Version 0.51", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SimPL-2.0", name: "Simple Public License 2.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SISSL", name: "Sun Industry Standards Source License v1.1", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "SISSL-1.2", name: "Sun Industry Standards Source License v1.2", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "SL", name: "SL License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Sleepycat", name: "Sleepycat License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "SMAIL-GPL", name: "SMAIL General Public License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SMLNJ", name: "Standard ML of New Jersey License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "SMPPL", name: "Secure Messaging Protocol Public License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SNIA", name: "SNIA Public License 1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "snprintf", name: "snprintf License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SOFA", name: "SOFA Software License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "softSurfer", name: "softSurfer License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Soundex", name: "Soundex License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Spencer-86", name: "Spencer License 86", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Spencer-94", name: "Spencer License 94", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Spencer-99", name: "Spencer License 99", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "SPL-1.0", name: "Sun Public License v1.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "ssh-keyscan", name: "ssh-keyscan License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SSH-OpenSSH", name: "SSH OpenSSH license", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SSH-short", name: "SSH short notice", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SSLeay-standalone", name: "SSLeay License - standalone", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SSPL-1.0", name: "Server Side Public License, v 1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::NetworkCopyleft },
+    LicenseId { id: "StandardML-NJ", name: "Standard ML of New Jersey License", deprecated: true, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "SugarCRM-1.1.3", name: "SugarCRM Public License v1.1.3", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SUL-1.0", name: "Sustainable Use License v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Sun-PPP", name: "Sun PPP License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Sun-PPP-2000", name: "Sun PPP License (2000)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SunPro", name: "SunPro License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "SWL", name: "Scheme Widget Library (SWL) Software License Agreement", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "swrule", name: "swrule License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Symlinks", name: "Symlinks License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TAPR-OHL-1.0", name: "TAPR Open Hardware License v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TCL", name: "TCL/TK License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "TCP-wrappers", name: "TCP Wrappers License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TermReadKey", name: "TermReadKey License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TGPPL-1.0", name: "Transitive Grace Period Public Licence 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ThirdEye", name: "ThirdEye License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "threeparttable", name: "threeparttable License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TMate", name: "TMate Open Source License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TORQUE-1.1", name: "TORQUE v2.5+ Software License v1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TOSL", name: "Trusster Open Source License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TPDL", name: "Time::ParseDate License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TPL-1.0", name: "THOR Public License 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TrustedQSL", name: "TrustedQSL License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TTWL", name: "Text-Tabs+Wrap License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TTYP0", name: "TTYP0 License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TU-Berlin-1.0", name: "Technische Universitaet Berlin License 1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "TU-Berlin-2.0", name: "Technische Universitaet Berlin License 2.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Ubuntu-font-1.0", name: "Ubuntu Font Licence v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "UCAR", name: "UCAR License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "UCL-1.0", name: "Upstream Compatibility License v1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "ulem", name: "ulem License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "UMich-Merit", name: "Michigan/Merit Networks License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Unicode-3.0", name: "Unicode License v3", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Unicode-DFS-2015", name: "Unicode License Agreement - Data Files and Software (2015)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Unicode-DFS-2016", name: "Unicode License Agreement - Data Files and Software (2016)", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Unicode-TOU", name: "Unicode Terms of Use", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "UnixCrypt", name: "UnixCrypt License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Unlicense", name: "The Unlicense", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::PublicDomain },
+    LicenseId { id: "Unlicense-libtelnet", name: "Unlicense - libtelnet variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Unlicense-libwhirlpool", name: "Unlicense - libwhirlpool variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "UPL-1.0", name: "Universal Permissive License v1.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "URT-RLE", name: "Utah Raster Toolkit Run Length Encoded License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Vim", name: "Vim License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "VOSTROM", name: "VOSTROM Public License for Open Source", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "VSL-1.0", name: "Vovida Software License v1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "W3C", name: "W3C Software Notice and License (2002-12-31)", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "W3C-19980720", name: "W3C Software Notice and License (1998-07-20)", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "W3C-20150513", name: "W3C Software Notice and Document License (2015-05-13)", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "w3m", name: "w3m License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Watcom-1.0", name: "Sybase Open Watcom Public License 1.0", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::NetworkCopyleft },
+    LicenseId { id: "Widget-Workshop", name: "Widget Workshop License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Wsuipa", name: "Wsuipa License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "WTFPL", name: "Do What The F*ck You Want To Public License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::PublicDomain },
+    LicenseId { id: "wwl", name: "WWL License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "wxWindows", name: "wxWindows Library License", deprecated: true, osi_approved: true, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "X11", name: "X11 License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "X11-distribute-modifications-variant", name: "X11 License Distribution Modification Variant", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "X11-swapped", name: "X11 swapped final paragraphs", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "Xdebug-1.03", name: "Xdebug License v 1.03", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Xerox", name: "Xerox License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Xfig", name: "Xfig License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "XFree86-1.1", name: "XFree86 License 1.1", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "xinetd", name: "xinetd License", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::StrongCopyleft },
+    LicenseId { id: "xkeyboard-config-Zinoviev", name: "xkeyboard-config Zinoviev License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "xlock", name: "xlock License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Xnet", name: "X.Net License", deprecated: false, osi_approved: true, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "xpp", name: "XPP License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "XSkat", name: "XSkat License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "xzoom", name: "xzoom License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "YPL-1.0", name: "Yahoo! Public License v1.0", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::WeakCopyleft },
+    LicenseId { id: "YPL-1.1", name: "Yahoo! Public License v1.1", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::WeakCopyleft },
+    LicenseId { id: "Zed", name: "Zed License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Zeeff", name: "Zeeff License", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Zend-2.0", name: "Zend License v2.0", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "Zimbra-1.3", name: "Zimbra Public License v1.3", deprecated: false, osi_approved: false, fsf_libre: true, category: Category::Other },
+    LicenseId { id: "Zimbra-1.4", name: "Zimbra Public License v1.4", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Other },
+    LicenseId { id: "Zlib", name: "zlib License", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "zlib-acknowledgement", name: "zlib/libpng License with Acknowledgement", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "ZPL-1.1", name: "Zope Public License 1.1", deprecated: false, osi_approved: false, fsf_libre: false, category: Category::Permissive },
+    LicenseId { id: "ZPL-2.0", name: "Zope Public License 2.0", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+    LicenseId { id: "ZPL-2.1", name: "Zope Public License 2.1", deprecated: false, osi_approved: true, fsf_libre: true, category: Category::Permissive },
+];
+
+pub const EXCEPTIONS: &[ExceptionId] = &[
+    ExceptionId { id: "389-exception", deprecated: false },
+    ExceptionId { id: "Asterisk-exception", deprecated: false },
+    ExceptionId { id: "Asterisk-linking-protocols-exception", deprecated: false },
+    ExceptionId { id: "Autoconf-exception-2.0", deprecated: false },
+    ExceptionId { id: "Autoconf-exception-3.0", deprecated: false },
+    ExceptionId { id: "Autoconf-exception-generic", deprecated: false },
+    ExceptionId { id: "Autoconf-exception-generic-3.0", deprecated: false },
+    ExceptionId { id: "Autoconf-exception-macro", deprecated: false },
+    ExceptionId { id: "Bison-exception-1.24", deprecated: false },
+    ExceptionId { id: "Bison-exception-2.2", deprecated: false },
+    ExceptionId { id: "Bootloader-exception", deprecated: false },
+    ExceptionId { id: "CGAL-linking-exception", deprecated: false },
+    ExceptionId { id: "Classpath-exception-2.0", deprecated: false },
+    ExceptionId { id: "CLISP-exception-2.0", deprecated: false },
+    ExceptionId { id: "cryptsetup-OpenSSL-exception", deprecated: false },
+    ExceptionId { id: "Digia-Qt-LGPL-exception-1.1", deprecated: false },
+    ExceptionId { id: "DigiRule-FOSS-exception", deprecated: false },
+    ExceptionId { id: "eCos-exception-2.0", deprecated: false },
+    ExceptionId { id: "erlang-otp-linking-exception", deprecated: false },
+    ExceptionId { id: "Fawkes-Runtime-exception", deprecated: false },
+    ExceptionId { id: "FLTK-exception", deprecated: false },
+    ExceptionId { id: "fmt-exception", deprecated: false },
+    ExceptionId { id: "Font-exception-2.0", deprecated: false },
+    ExceptionId { id: "freertos-exception-2.0", deprecated: false },
+    ExceptionId { id: "GCC-exception-2.0", deprecated: false },
+    ExceptionId { id: "GCC-exception-2.0-note", deprecated: false },
+    ExceptionId { id: "GCC-exception-3.1", deprecated: false },
+    ExceptionId { id: "Gmsh-exception", deprecated: false },
+    ExceptionId { id: "GNAT-exception", deprecated: false },
+    ExceptionId { id: "GNOME-examples-exception", deprecated: false },
+    ExceptionId { id: "GNU-compiler-exception", deprecated: false },
+    ExceptionId { id: "gnu-javamail-exception", deprecated: false },
+    ExceptionId { id: "GPL-3.0-389-ds-base-exception", deprecated: false },
+    ExceptionId { id: "GPL-3.0-interface-exception", deprecated: false },
+    ExceptionId { id: "GPL-3.0-linking-exception", deprecated: false },
+    ExceptionId { id: "GPL-3.0-linking-source-exception", deprecated: false },
+    ExceptionId { id: "GPL-CC-1.0", deprecated: false },
+    ExceptionId { id: "GStreamer-exception-2005", deprecated: false },
+    ExceptionId { id: "GStreamer-exception-2008", deprecated: false },
+    ExceptionId { id: "harbour-exception", deprecated: false },
+    ExceptionId { id: "i2p-gpl-java-exception", deprecated: false },
+    ExceptionId { id: "Independent-modules-exception", deprecated: false },
+    ExceptionId { id: "KiCad-libraries-exception", deprecated: false },
+    ExceptionId { id: "LGPL-3.0-linking-exception", deprecated: false },
+    ExceptionId { id: "libpri-OpenH323-exception", deprecated: false },
+    ExceptionId { id: "Libtool-exception", deprecated: false },
+    ExceptionId { id: "Linux-syscall-note", deprecated: false },
+    ExceptionId { id: "LLGPL", deprecated: false },
+    ExceptionId { id: "LLVM-exception", deprecated: false },
+    ExceptionId { id: "LZMA-exception", deprecated: false },
+    ExceptionId { id: "mif-exception", deprecated: false },
+    ExceptionId { id: "mxml-exception", deprecated: false },
+    ExceptionId { id: "Nokia-Qt-exception-1.1", deprecated: true },
+    ExceptionId { id: "OCaml-LGPL-linking-exception", deprecated: false },
+    ExceptionId { id: "OCCT-exception-1.0", deprecated: false },
+    ExceptionId { id: "OpenJDK-assembly-exception-1.0", deprecated: false },
+    ExceptionId { id: "openvpn-openssl-exception", deprecated: false },
+    ExceptionId { id: "PCRE2-exception", deprecated: false },
+    ExceptionId { id: "polyparse-exception", deprecated: false },
+    ExceptionId { id: "PS-or-PDF-font-exception-20170817", deprecated: false },
+    ExceptionId { id: "QPL-1.0-INRIA-2004-exception", deprecated: false },
+    ExceptionId { id: "Qt-GPL-exception-1.0", deprecated: false },
+    ExceptionId { id: "Qt-LGPL-exception-1.1", deprecated: false },
+    ExceptionId { id: "Qwt-exception-1.0", deprecated: false },
+    ExceptionId { id: "romic-exception", deprecated: false },
+    ExceptionId { id: "RRDtool-FLOSS-exception-2.0", deprecated: false },
+    ExceptionId { id: "SANE-exception", deprecated: false },
+    ExceptionId { id: "SHL-2.0", deprecated: false },
+    ExceptionId { id: "SHL-2.1", deprecated: false },
+    ExceptionId { id: "stunnel-exception", deprecated: false },
+    ExceptionId { id: "SWI-exception", deprecated: false },
+    ExceptionId { id: "Swift-exception", deprecated: false },
+    ExceptionId { id: "Texinfo-exception", deprecated: false },
+    ExceptionId { id: "u-boot-exception-2.0", deprecated: false },
+    ExceptionId { id: "UBDL-exception", deprecated: false },
+    ExceptionId { id: "Universal-FOSS-exception-1.0", deprecated: false },
+    ExceptionId { id: "vsftpd-openssl-exception", deprecated: false },
+    ExceptionId { id: "WxWindows-exception-3.1", deprecated: false },
+    ExceptionId { id: "x11vnc-openssl-exception", deprecated: false },
+];
@@ -0,0 +1,534 @@
+//! License templates using the markup from the SPDX license list, where
+//! `<<var;name="...";original="...";match="...">>` marks text that varies
+//! between copies of a license, like the copyright holder, and text between
+//! `<<beginOptional>>` and `<<endOptional>>` may be left out.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use regex::{self, Regex};
+
+/// The length of the runs of words compared between texts, so that reordered
+/// text doesn't match
+const NGRAM: usize = 3;
+
+#[derive(Debug)]
+enum Part {
+    Text(String),
+    Var { name: String, original: String },
+    Optional(Vec<Part>),
+}
+
+pub struct Template {
+    parts: Vec<Part>,
+    /// The source of a regex matching the words of a text, see `Patterns`
+    pattern: String,
+    /// The variables captured by the pattern, in order
+    names: Vec<String>,
+    patterns: RefCell<Option<Patterns>>,
+    /// The words of the template split at each variable, with and without
+    /// the optional sections, and their n-grams
+    segments: Vec<Vec<Vec<String>>>,
+    ngrams: Vec<HashMap<u64, u32>>,
+    /// How many n-grams there are in each of `ngrams`
+    lengths: Vec<u32>,
+}
+
+/// Regexes for a template, compiled when a text is first matched against it
+/// as they're slow to build for the longer licenses, `None` if they're too
+/// big to compile
+struct Patterns {
+    /// Matches a text that is exactly the license
+    exact: Option<Regex>,
+    /// Matches a text containing the license along with something else
+    within: Option<Regex>,
+}
+
+/// The result of matching a text against a template
+pub struct Match {
+    /// How similar the text is to the template, from 0 to 1
+    pub score: f32,
+    /// The text found in place of each variable, only available when the text
+    /// matches the template exactly apart from case, punctuation and
+    /// whitespace
+    pub variables: BTreeMap<String, String>,
+    /// Whether the text has the whole license along with other text, which
+    /// may change its terms
+    pub extended: bool,
+}
+
+/// A word in a license text and where it is in the original
+struct Word {
+    text: String,
+    start: usize,
+    end: usize,
+    /// Whether it's on a line giving a copyright notice
+    copyright: bool,
+}
+
+/// A license text split into the words that matter when comparing it, so it
+/// only has to be done once however many templates it's compared to
+pub struct Text<'a> {
+    source: &'a str,
+    words: Vec<Word>,
+    /// The words each followed by a space, and where each of them starts
+    joined: String,
+    offsets: Vec<usize>,
+    /// The words outside of copyright notices and their n-grams, which are
+    /// what is compared for similarity
+    significant: Vec<String>,
+    ngrams: HashMap<u64, u32>,
+}
+
+/// Parses templates and license texts, holding the regexes used to do so
+pub struct Parser {
+    markup: Regex,
+    attribute: Regex,
+    word: Regex,
+    bullet: Regex,
+}
+
+impl Parser {
+    pub fn new() -> Parser {
+        Parser {
+            markup: Regex::new(r#"<<(beginOptional|endOptional)[^>]*>>|<<var((?:\s*;\s*\w+\s*=\s*"[^"]*")*)\s*>>"#).unwrap(),
+            attribute: Regex::new(r#"(\w+)\s*=\s*"([^"]*)""#).unwrap(),
+            word: Regex::new(r"\w+").unwrap(),
+            bullet: Regex::new(r"^(?i:\(?([0-9]+|[a-z]|[ivx]+)[.)]|[-*•])\s+").unwrap(),
+        }
+    }
+
+    pub fn template(&self, template: &str) -> Template {
+        let mut stack = vec![Vec::new()];
+        let mut last = 0;
+        for captures in self.markup.captures_iter(template) {
+            let whole = captures.get(0).unwrap();
+            if whole.start() > last {
+                stack.last_mut().unwrap().push(Part::Text(template[last..whole.start()].to_owned()));
+            }
+            last = whole.end();
+            match captures.get(1).map(|tag| tag.as_str()) {
+                Some("beginOptional") => stack.push(Vec::new()),
+                Some(_) => {
+                    // Ignore an unbalanced end, it's the best we can do with
+                    // a broken template
+                    if stack.len() > 1 {
+                        let parts = stack.pop().unwrap();
+                        stack.last_mut().unwrap().push(Part::Optional(parts));
+                    }
+                }
+                None => {
+                    let mut name = String::new();
+                    let mut original = String::new();
+                    for attribute in self.attribute.captures_iter(&captures[2]) {
+                        match &attribute[1] {
+                            "name" => name = attribute[2].to_owned(),
+                            "original" => original = attribute[2].to_owned(),
+                            _ => (),
+                        }
+                    }
+                    stack.last_mut().unwrap().push(Part::Var { name, original });
+                }
+            }
+        }
+        if last < template.len() {
+            stack.last_mut().unwrap().push(Part::Text(template[last..].to_owned()));
+        }
+        while stack.len() > 1 {
+            let parts = stack.pop().unwrap();
+            stack.last_mut().unwrap().push(Part::Optional(parts));
+        }
+        let parts = stack.pop().unwrap();
+
+        let mut names = Vec::new();
+        let mut pattern = String::new();
+        self.pattern(&parts, &mut names, &mut pattern);
+
+        let mut segments = Vec::new();
+        let mut ngrams = Vec::new();
+        for &optional in &[true, false] {
+            let mut result = vec![Vec::new()];
+            self.segments(&parts, optional, &mut result);
+            ngrams.push(segment_ngrams(&result, NGRAM));
+            segments.push(result);
+        }
+
+        Template {
+            parts,
+            pattern,
+            names,
+            patterns: RefCell::new(None),
+            segments,
+            lengths: ngrams.iter().map(|ngrams| ngrams.values().sum()).collect(),
+            ngrams,
+        }
+    }
+
+    /// Builds a regex matching the words of a text against the template, with
+    /// a group capturing each variable
+    fn pattern(&self, parts: &[Part], names: &mut Vec<String>, source: &mut String) {
+        for part in parts {
+            match *part {
+                Part::Text(ref text) => {
+                    for word in self.words(text) {
+                        source.push_str(&regex::escape(&word.text));
+                        source.push(' ');
+                    }
+                }
+                Part::Var { ref name, .. } => {
+                    source.push_str(&format!("(?P<v{}>(?:\\S+ )*?)", names.len()));
+                    names.push(name.clone());
+                }
+                Part::Optional(ref parts) => {
+                    source.push_str("(?:");
+                    self.pattern(parts, names, source);
+                    source.push_str(")?");
+                }
+            }
+        }
+    }
+
+    /// The words of the template that count towards similarity, split where
+    /// variables and optional sections are so n-grams don't cross them
+    fn segments(&self, parts: &[Part], optional: bool, result: &mut Vec<Vec<String>>) {
+        for part in parts {
+            match *part {
+                Part::Text(ref text) => {
+                    let words = self.words(text).into_iter().filter(|word| !word.copyright).map(|word| word.text);
+                    result.last_mut().unwrap().extend(words);
+                }
+                Part::Var { .. } => result.push(Vec::new()),
+                Part::Optional(ref parts) => {
+                    result.push(Vec::new());
+                    if optional {
+                        self.segments(parts, optional, result);
+                        result.push(Vec::new());
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn text<'a>(&self, text: &'a str) -> Text<'a> {
+        let words = self.words(text);
+
+        let mut joined = String::new();
+        let mut offsets = Vec::with_capacity(words.len());
+        for word in &words {
+            offsets.push(joined.len());
+            joined.push_str(&word.text);
+            joined.push(' ');
+        }
+
+        let significant = words.iter().filter(|word| !word.copyright).map(|word| word.text.clone()).collect::<Vec<_>>();
+        let mut ngrams = HashMap::new();
+        add_ngrams(&significant, NGRAM, &mut ngrams);
+
+        Text {
+            source: text,
+            words,
+            joined,
+            offsets,
+            significant,
+            ngrams,
+        }
+    }
+
+    /// Splits a license text into the words that matter when comparing it,
+    /// as in the SPDX matching guidelines case, punctuation and bullets are
+    /// ignored
+    fn words(&self, text: &str) -> Vec<Word> {
+        let mut words = Vec::new();
+        let mut offset = 0;
+        for line in text.split('\n') {
+            // Comment markers from license headers copied out of source files
+            let trimmed = line.trim_start().trim_start_matches(|c| c == '/' || c == '#' || c == '*').trim_start();
+            let lower = trimmed.to_lowercase();
+            let copyright = lower.starts_with("copyright") || lower.starts_with('©') || lower.starts_with("(c)");
+            let skip = (line.len() - trimmed.len()) + self.bullet.find(trimmed).map_or(0, |bullet| bullet.end());
+            for found in self.word.find_iter(line) {
+                if found.start() < skip {
+                    continue;
+                }
+                let text = match &found.as_str().to_lowercase()[..] {
+                    "licence" => "license".to_owned(),
+                    "licences" => "licenses".to_owned(),
+                    text => text.to_owned(),
+                };
+                words.push(Word {
+                    text,
+                    start: offset + found.start(),
+                    end: offset + found.end(),
+                    copyright,
+                });
+            }
+            offset += line.len() + 1;
+        }
+        words
+    }
+}
+
+impl Template {
+    /// The text of the license as it is given on the SPDX license list
+    pub fn text(&self) -> String {
+        self.fill(&BTreeMap::new())
+    }
+
+    /// The text of the license with the given values in place of variables,
+    /// any others keep the text from the SPDX license list
+    pub fn fill(&self, variables: &BTreeMap<String, String>) -> String {
+        self.render(variables, true)
+    }
+
+    /// Like `fill`, leaving out the optional sections of the license
+    pub fn fill_required(&self, variables: &BTreeMap<String, String>) -> String {
+        self.render(variables, false)
+    }
+
+    fn render(&self, variables: &BTreeMap<String, String>, optional: bool) -> String {
+        fn render(parts: &[Part], variables: &BTreeMap<String, String>, optional: bool, text: &mut String) {
+            for part in parts {
+                match *part {
+                    Part::Text(ref s) => text.push_str(s),
+                    Part::Var { ref name, ref original } => text.push_str(variables.get(name).unwrap_or(original)),
+                    Part::Optional(ref parts) => if optional {
+                        render(parts, variables, optional, text)
+                    },
+                }
+            }
+        }
+
+        let mut text = String::new();
+        render(&self.parts, variables, optional, &mut text);
+        text
+    }
+
+    /// Whether a variable is outside of the optional sections of the
+    /// license, unlike the copyright notice in the appendix of Apache-2.0
+    pub fn is_required(&self, name: &str) -> bool {
+        self.parts.iter().any(|part| match *part {
+            Part::Var { name: ref var, .. } => var == name,
+            _ => false,
+        })
+    }
+
+    pub fn matches(&self, text: &Text) -> Match {
+        let mut patterns = self.patterns.borrow_mut();
+        let patterns = patterns.get_or_insert_with(|| Patterns {
+            exact: Regex::new(&format!("^{}$", self.pattern)).ok(),
+            within: Regex::new(&self.pattern).ok(),
+        });
+
+        if let Some(captures) = patterns.exact.as_ref().and_then(|exact| exact.captures(&text.joined)) {
+            let mut variables = BTreeMap::new();
+            for (i, name) in self.names.iter().enumerate() {
+                if let Some(capture) = captures.name(&format!("v{}", i)) {
+                    if capture.start() == capture.end() {
+                        continue;
+                    }
+                    let first = text.offsets.binary_search(&capture.start()).unwrap_or_else(|i| i);
+                    let last = text.offsets.binary_search(&(capture.end() - 1)).unwrap_or_else(|i| i - 1);
+                    variables.insert(name.clone(), text.source[text.words[first].start..text.words[last].end].to_owned());
+                }
+            }
+            return Match { score: 1.0, variables, extended: false };
+        }
+
+        Match {
+            score: self.score(text),
+            variables: BTreeMap::new(),
+            extended: patterns.within.as_ref().map_or(false, |within| within.is_match(&text.joined)),
+        }
+    }
+
+    /// The best similarity of the text to the template with or without its
+    /// optional sections, variables don't count either way, for quickly
+    /// comparing against many templates
+    pub fn score(&self, text: &Text) -> f32 {
+        let mut best: f32 = 0.0;
+        for (segments, ngrams) in self.segments.iter().zip(&self.ngrams) {
+            let score = if text.significant.len() >= NGRAM {
+                similarity(&text.ngrams, ngrams)
+            } else {
+                // Too short for the usual n-grams, compare shorter runs
+                let n = text.significant.len().max(1);
+                let mut text_ngrams = HashMap::new();
+                add_ngrams(&text.significant, n, &mut text_ngrams);
+                similarity(&text_ngrams, &segment_ngrams(segments, n))
+            };
+            best = best.max(score);
+        }
+        best
+    }
+
+    /// The most the text could score against the template going by only how
+    /// many n-grams they have, to cheaply rule out templates of a very
+    /// different length before scoring them
+    pub fn max_score(&self, text: &Text) -> f32 {
+        if text.significant.len() < NGRAM {
+            return 1.0;
+        }
+        let length = (text.significant.len() - NGRAM + 1) as f32;
+        self.lengths.iter()
+            .map(|&template| 2.0 * length.min(template as f32) / (length + template as f32))
+            .fold(0.0, f32::max)
+    }
+}
+
+/// Counts the n-grams of some words, by their hash as there are a lot of them
+fn add_ngrams(words: &[String], n: usize, ngrams: &mut HashMap<u64, u32>) {
+    for ngram in words.windows(n) {
+        let mut hasher = DefaultHasher::new();
+        ngram.hash(&mut hasher);
+        *ngrams.entry(hasher.finish()).or_insert(0) += 1;
+    }
+}
+
+/// The n-grams of the segments of a template, n-grams don't cross between
+/// segments
+fn segment_ngrams(segments: &[Vec<String>], n: usize) -> HashMap<u64, u32> {
+    let mut ngrams = HashMap::new();
+    for segment in segments {
+        add_ngrams(segment, n, &mut ngrams);
+    }
+    ngrams
+}
+
+/// The Dice coefficient of the word n-grams in a text and a template
+fn similarity(text: &HashMap<u64, u32>, template: &HashMap<u64, u32>) -> f32 {
+    let common: u32 = template.iter()
+        .map(|(ngram, &count)| count.min(text.get(ngram).cloned().unwrap_or(0)))
+        .sum();
+    let total: u32 = text.values().sum::<u32>() + template.values().sum::<u32>();
+
+    if total == 0 {
+        0.0
+    } else {
+        2.0 * (common as f32) / (total as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::Parser;
+
+    const MIT: &str = include_str!("licenses/MIT");
+
+    const TEMPLATE: &str = r#"<<var;name="copyright";original="Copyright (c) <year> <owner>";match=".+">>
+
+Permission is granted to use this software for any purpose<<beginOptional>>, with or without fee<<endOptional>>, provided that this notice is kept in all copies of it.
+
+THE SOFTWARE IS PROVIDED AS IS, WITHOUT ANY WARRANTY OF ANY KIND."#;
+
+    #[test]
+    fn exact_match_captures_variables() {
+        let parser = Parser::new();
+        let template = parser.template(MIT);
+        let text = template.text().replace("Copyright <YEAR> <COPYRIGHT HOLDER>", "Copyright (c) 2016 Jane Doe");
+        let found = template.matches(&parser.text(&text));
+        assert_eq!(found.score, 1.0);
+        assert!(!found.extended);
+        assert_eq!(found.variables.get("copyright").map(|s| &s[..]), Some("Copyright (c) 2016 Jane Doe"));
+    }
+
+    #[test]
+    fn ignores_case_punctuation_and_whitespace() {
+        let parser = Parser::new();
+        let template = parser.template(TEMPLATE);
+        let text = "copyright 2020 someone\n\n  permission is GRANTED to use this software\nfor any purpose - provided that this notice is kept in all copies of it\n\n\
+                    The Software is provided \"as is\" without any warranty of any kind";
+        let found = template.matches(&parser.text(text));
+        assert_eq!(found.score, 1.0);
+        assert_eq!(found.variables.get("copyright").map(|s| &s[..]), Some("copyright 2020 someone"));
+    }
+
+    #[test]
+    fn optional_sections() {
+        let parser = Parser::new();
+        let template = parser.template(TEMPLATE);
+        let with = template.text();
+        let without = with.replace(", with or without fee", "");
+        assert_ne!(with, without);
+        assert_eq!(template.matches(&parser.text(&with)).score, 1.0);
+        assert_eq!(template.matches(&parser.text(&without)).score, 1.0);
+    }
+
+    #[test]
+    fn extra_text_is_not_an_exact_match() {
+        let parser = Parser::new();
+        let template = parser.template(MIT);
+        let text = format!("{}\n\nThe Software shall not be used for evil.\n", template.text());
+        let found = template.matches(&parser.text(&text));
+        assert!(found.score < 1.0);
+        assert!(found.score > 0.9);
+        assert!(found.extended);
+        assert!(found.variables.is_empty());
+    }
+
+    #[test]
+    fn reordered_text_scores_lower() {
+        let parser = Parser::new();
+        let template = parser.template(MIT);
+        let text = template.text();
+        let mut reordered = text.split("\n\n").collect::<Vec<_>>();
+        reordered.reverse();
+        let mut shuffled = text.split_whitespace().collect::<Vec<_>>();
+        shuffled.sort();
+
+        let exact = template.matches(&parser.text(&text)).score;
+        let reordered = template.matches(&parser.text(&reordered.join("\n\n"))).score;
+        let shuffled = template.matches(&parser.text(&shuffled.join(" "))).score;
+        assert_eq!(exact, 1.0);
+        assert!(reordered < exact);
+        assert!(shuffled < reordered);
+        assert!(shuffled < 0.5);
+    }
+
+    #[test]
+    fn copyright_notices_dont_count_towards_similarity() {
+        let parser = Parser::new();
+        let template = parser.template(MIT);
+        let mut text = String::new();
+        for year in 2000..2040 {
+            text.push_str(&format!("Copyright (c) {} The Project Developers and Contributors\n", year));
+        }
+        let license = template.text().replace("Copyright <YEAR> <COPYRIGHT HOLDER>", "");
+        text.push_str(&license);
+        assert_eq!(template.score(&parser.text(&text)), template.score(&parser.text(&license)));
+        assert!(template.score(&parser.text(&text)) > 0.95);
+    }
+
+    #[test]
+    fn max_score_bounds_score() {
+        let parser = Parser::new();
+        let template = parser.template(MIT);
+        let full = template.text();
+        let words = full.split_whitespace().collect::<Vec<_>>();
+        for &length in &[1, 2, 10, 50, 100, words.len()] {
+            let prefix = words[..length].join(" ");
+            let text = parser.text(&prefix);
+            assert!(template.max_score(&text) >= template.score(&text), "with the first {} words", length);
+        }
+        let doubled = full.clone() + &full;
+        let text = parser.text(&doubled);
+        assert!(template.max_score(&text) >= template.score(&text));
+        assert!(template.max_score(&text) < 0.85);
+        let prefix = words[..50].join(" ");
+        assert!(template.max_score(&parser.text(&prefix)) < 0.85);
+    }
+
+    #[test]
+    fn fill() {
+        let parser = Parser::new();
+        let template = parser.template(TEMPLATE);
+        let mut variables = BTreeMap::new();
+        variables.insert("copyright".to_owned(), "Copyright (c) 2020 Someone".to_owned());
+        let text = template.fill(&variables);
+        assert!(text.starts_with("Copyright (c) 2020 Someone\n"));
+        assert!(template.text().starts_with("Copyright (c) <year> <owner>\n"));
+    }
+}
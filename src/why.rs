@@ -0,0 +1,220 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::hash::Hash;
+
+use cargo::core::dependency::Kind;
+use cargo::core::{PackageId, PackageIdSpec};
+use cargo::{Config, CargoResult};
+
+use load::Graph;
+
+/// The most dependency chains shown for a package, there can be
+/// exponentially many in a large graph
+const MAX_CHAINS: usize = 64;
+
+pub fn run(graph: &Graph, spec: &PackageIdSpec) -> CargoResult<()> {
+    let id = spec.query(graph.packages.iter().map(|package| package.package_id()))?;
+
+    let (chains, truncated) = chains(graph, id);
+    for chain in chains {
+        println!("{}", chain);
+    }
+    if truncated {
+        println!("... and more, only the first {} are shown", MAX_CHAINS);
+    }
+
+    Ok(())
+}
+
+/// Prints why a package that `check` reported on is included
+pub fn explain(config: &Config, graph: &Graph, id: &PackageId, error: bool) -> CargoResult<()> {
+    let (chains, truncated) = chains(graph, id);
+    let mut messages = chains.into_iter().map(|chain| format!("    via {}", chain)).collect::<Vec<_>>();
+    if truncated {
+        messages.push(format!("    ... and more, only the first {} are shown", MAX_CHAINS));
+    }
+    for message in messages {
+        if error {
+            config.shell().error(message)?;
+        } else {
+            config.shell().warn(message)?;
+        }
+    }
+    Ok(())
+}
+
+/// Every way a package is included, as the chains of dependencies from the
+/// roots of the graph that don't go through any package twice, shortest first
+/// and formatted like `root 0.1.0 -> dep 1.0.0 (normal, feature "foo")`. Also
+/// returns whether there were more than `MAX_CHAINS` of them
+pub fn chains(graph: &Graph, id: &PackageId) -> (Vec<String>, bool) {
+    let mut roots = graph.roots.iter().collect::<Vec<_>>();
+    roots.sort();
+
+    let (paths, truncated) = paths(&roots, id, MAX_CHAINS, |id| graph.dependencies(id), |edge| &edge.id);
+    let chains = paths.into_iter()
+        .map(|(root, edges)| {
+            let mut line = format!("{} {}", root.name(), root.version());
+            for edge in edges {
+                write!(line, " -> {} {} ({}", edge.id.name(), edge.id.version(), match edge.kind {
+                    Kind::Normal => "normal",
+                    Kind::Build => "build",
+                    Kind::Development => "dev",
+                }).unwrap();
+                if let Some(ref feature) = edge.feature {
+                    write!(line, ", feature \"{}\"", feature).unwrap();
+                }
+                line.push(')');
+            }
+            line
+        })
+        .collect();
+    (chains, truncated)
+}
+
+/// Finds up to `max` paths from the roots to `target` that don't visit a
+/// node twice, shortest first, along with whether there were more. Paths only
+/// go through other roots to reach them as the target, as roots are only
+/// depended on by each other through development dependencies
+fn paths<'a, N, E, F, G>(roots: &[N], target: N, max: usize, edges: F, to: G) -> (Vec<(N, Vec<&'a E>)>, bool)
+        where N: Copy + Eq + Hash, F: Fn(N) -> &'a [E], G: Fn(&'a E) -> N {
+    let follow = |node: N| node == target || !roots.contains(&node);
+
+    // Only search through nodes that lead to the target, so that time isn't
+    // spent walking paths that can't reach it
+    let mut reachable = roots.to_vec();
+    let mut next = 0;
+    while next < reachable.len() {
+        for edge in edges(reachable[next]) {
+            let node = to(edge);
+            if follow(node) && !reachable.contains(&node) {
+                reachable.push(node);
+            }
+        }
+        next += 1;
+    }
+    let mut leads = HashSet::new();
+    leads.insert(target);
+    loop {
+        let before = leads.len();
+        for &node in &reachable {
+            if edges(node).iter().any(|edge| follow(to(edge)) && leads.contains(&to(edge))) {
+                leads.insert(node);
+            }
+        }
+        if leads.len() == before {
+            break;
+        }
+    }
+
+    let mut found = Vec::new();
+    let mut truncated = false;
+    'roots: for &root in roots {
+        if root == target {
+            found.push((root, Vec::new()));
+            continue;
+        }
+        if !leads.contains(&root) {
+            continue;
+        }
+        // Depth first, keeping the edges left to try from each node on the
+        // current path
+        let mut nodes = vec![root];
+        let mut path = Vec::new();
+        let mut remaining = vec![edges(root).iter()];
+        while let Some(next) = remaining.last_mut().map(|edges| edges.next()) {
+            let edge = match next {
+                Some(edge) => edge,
+                None => {
+                    remaining.pop();
+                    nodes.pop();
+                    path.pop();
+                    continue;
+                }
+            };
+            let node = to(edge);
+            if node == target {
+                if found.len() == max {
+                    truncated = true;
+                    break 'roots;
+                }
+                let mut path = path.clone();
+                path.push(edge);
+                found.push((root, path));
+            } else if follow(node) && leads.contains(&node) && !nodes.contains(&node) {
+                nodes.push(node);
+                path.push(edge);
+                remaining.push(edges(node).iter());
+            }
+        }
+    }
+
+    found.sort_by_key(|&(_, ref path)| path.len());
+    (found, truncated)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::paths;
+
+    type Edge = (&'static str, &'static str);
+
+    fn find(edges: &[Edge], roots: &[&'static str], target: &'static str, max: usize) -> (Vec<String>, bool) {
+        let mut graph = HashMap::new();
+        for edge in edges {
+            graph.entry(edge.0).or_insert_with(Vec::new).push(*edge);
+        }
+        let (paths, truncated) = paths(roots, target, max, |node| graph.get(node).map(|edges| &edges[..]).unwrap_or(&[]), |edge| edge.1);
+        let paths = paths.into_iter()
+            .map(|(root, edges)| edges.iter().fold(root.to_owned(), |path, edge| format!("{} -> {}", path, edge.1)))
+            .collect();
+        (paths, truncated)
+    }
+
+    #[test]
+    fn diamond() {
+        let edges = &[("a", "b"), ("a", "c"), ("b", "d"), ("c", "d"), ("d", "e")];
+        assert_eq!(find(edges, &["a"], "d", 10), (vec!["a -> b -> d".to_owned(), "a -> c -> d".to_owned()], false));
+        assert_eq!(find(edges, &["a"], "e", 10), (vec!["a -> b -> d -> e".to_owned(), "a -> c -> d -> e".to_owned()], false));
+    }
+
+    #[test]
+    fn shortest_first() {
+        let edges = &[("a", "b"), ("b", "c"), ("c", "d"), ("a", "d")];
+        assert_eq!(find(edges, &["a"], "d", 10).0, vec!["a -> d", "a -> b -> c -> d"]);
+    }
+
+    #[test]
+    fn cycles() {
+        let edges = &[("a", "b"), ("b", "c"), ("c", "b"), ("c", "d"), ("d", "a")];
+        assert_eq!(find(edges, &["a"], "d", 10), (vec!["a -> b -> c -> d".to_owned()], false));
+        assert_eq!(find(edges, &["a"], "a", 10).0, vec!["a"]);
+    }
+
+    #[test]
+    fn roots() {
+        let edges = &[("a", "x"), ("b", "x"), ("a", "b"), ("b", "y")];
+        assert_eq!(find(edges, &["a", "b"], "x", 10).0, vec!["a -> x", "b -> x"]);
+        assert_eq!(find(edges, &["a", "b"], "b", 10).0, vec!["b", "a -> b"]);
+        assert_eq!(find(edges, &["a", "b"], "y", 10).0, vec!["b -> y"]);
+    }
+
+    #[test]
+    fn capped() {
+        // Each layer doubles the number of paths
+        let mut edges = Vec::new();
+        let layers = ["a", "b", "c", "d", "e", "f", "g", "h", "i"];
+        let sides = ["1", "2", "3", "4", "5", "6", "7", "8"];
+        for (layer, side) in layers.windows(2).zip(&sides) {
+            edges.push((layer[0], *side));
+            edges.push((*side, layer[1]));
+            edges.push((layer[0], layer[1]));
+        }
+        let (paths, truncated) = find(&edges, &["a"], "i", 10);
+        assert_eq!(paths.len(), 10);
+        assert!(truncated);
+        assert_eq!(find(&edges, &["a"], "i", 256), (find(&edges, &["a"], "i", 300).0, false));
+    }
+}